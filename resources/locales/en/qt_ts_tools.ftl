@@ -1,6 +1,42 @@
 cli-about = Small command line utility to manipulate Qt's translation files with diverse operations.
+cli-config = Project configuration file to use instead of the "qt-ts-tools.toml" found in the working directory or its parents.
+cli-consistency-desc = Lists sources translated differently across contexts, and optionally rewrites them to the majority translation.
+cli-consistency-fix = Outputs the translation file with diverging translations rewritten to the majority translation and marked unfinished for review.
+cli-consistency-found = Found { $count } inconsistently translated source(s) in "{ $file }".
+cli-consistency-input = File path to check the consistency of translations from, or "@group" for a group of files of the project configuration.
+cli-consistency-no-majority = { $count } source(s) of "{ $file }" have no majority translation and were left untouched.
+cli-consistency-output = If specified, will produce output in a file at designated location instead of stdout.
+cli-consistency-source = "{ $source }" has { $count } different translations:
+cli-consistency-variant = "{ $translation }" used { $count } time(s) in { $contexts }
+cli-diff-change = { $field }: "{ $old }" → "{ $new }"
+cli-diff-contexts-added = Contexts added ({ $count }):
+cli-diff-contexts-removed = Contexts removed ({ $count }):
+cli-diff-contexts-reordered = The order of the contexts changed.
+cli-diff-desc = Compares two translation files by context and message, ignoring location and ordering changes.
+cli-diff-differences-found = Found { $count } difference(s) between both files.
+cli-diff-format = Format of the report: human readable text or JSON.
+cli-diff-locations = Also reports the messages whose locations changed.
+cli-diff-message = Context "{ $context }", source "{ $source }"
+cli-diff-messages-added = Messages added ({ $count }):
+cli-diff-messages-changed = Messages changed ({ $count }):
+cli-diff-messages-removed = Messages removed ({ $count }):
+cli-diff-messages-reordered = Contexts whose messages were reordered ({ $count }):
+cli-diff-new = Current version of the file, or "@group" for a group of a single file of the project configuration.
+cli-diff-no-differences = No differences.
+cli-diff-old = Previous version of the file, or "@group" for a group of a single file of the project configuration.
+cli-diff-order = Also reports the contexts and messages whose order changed.
+cli-diff-output = If specified, will produce output in a file at designated location instead of stdout.
+cli-diff-strategy = How messages of both versions are identified as the same message within a context: "source-comment-context" (default, as Qt does), "id", "source-comment" or "id-or-source".
+cli-duplicates-conflict = Conflicting translations: { $translations }
+cli-duplicates-conflicts-remaining = Collapsed duplicates of "{ $file }", but { $count } message(s) had conflicting translations to review.
+cli-duplicates-desc = Lists messages appearing more than once in a context, and optionally collapses them.
+cli-duplicates-fix = Outputs the translation file with duplicates collapsed: locations are merged and the finished translation is kept.
+cli-duplicates-found = Found { $count } duplicated message(s) in "{ $file }".
+cli-duplicates-input = File path to look for duplicated messages in, or "@group" for a group of files of the project configuration.
+cli-duplicates-message = "{ $source }" appears { $count } times ({ $locations }).
+cli-duplicates-output = If specified, will produce output in a file at designated location instead of stdout.
 cli-extract-desc = Extracts a translation type messages and contexts from the input translation file.
-cli-extract-input = File path to extract translations from.
+cli-extract-input = File path to extract translations from, or "@group" for a group of a single file of the project configuration.
 cli-extract-translation-type = Translation type list to extract into a single, valid translation output.
 cli-extract-output = If specified, will produce output in a file at designated location instead of stdout.
 cli-headers-commands = Commands
@@ -8,13 +44,88 @@ cli-headers-options = Options
 cli-headers-usage = Usage
 cli-headers-arguments = Arguments
 cli-help = Prints help information.
-cli-merge-desc = Merges two translation file contexts and messages into a single output.
+cli-lint-accelerator-duplicated = Mnemonic "{ $mnemonic }" is already used by "{ $other }" in the same context.
+cli-lint-accelerator-extra = Keyboard accelerator (&) in { $target } does not exist in the source.
+cli-lint-accelerator-missing = Keyboard accelerator (&) of the source is missing from { $target }.
+cli-lint-capitalization = Lowercase start in { $target } while the source starts with an uppercase letter.
+cli-lint-desc = Checks the translations of the input file for common mistakes.
+cli-lint-double-spaces = Doubled spaces in { $target }.
+cli-lint-ending-punctuation-added = Ending punctuation "{ $punctuation }" in { $target } does not exist in the source.
+cli-lint-ending-punctuation-changed = Ending punctuation "{ $punctuation }" in { $target } differs from "{ $expected }" in the source.
+cli-lint-ending-punctuation-missing = Ending punctuation "{ $punctuation }" of the source is missing from { $target }.
+cli-lint-format = Format in which problems are reported: human readable text, JSON, SARIF 2.1.0 or GitHub Actions annotations.
+cli-lint-glossary = Glossaries of terms to enforce: Qt phrase books (.qph) or tab separated "source, target" files. A "# language: de" header restricts a tab separated file to a language.
+cli-lint-glossary-match = How glossary terms are matched: "word" for whole words, "prefix" to accept any word ending (e.g. plurals, but also longer words).
+cli-lint-glossary-term = Glossary term "{ $term }" should be translated as "{ $expected }" in { $target }.
+cli-lint-input = File path to lint translations from, or "@group" for a group of files of the project configuration.
+cli-lint-leading-whitespace = Leading whitespace of { $target } differs from the source.
+cli-lint-length-unit = Unit in which the length of translations is measured.
+cli-lint-length-unit-chars = characters
+cli-lint-length-unit-utf16 = UTF-16 code units
+cli-lint-markup-entity-added = Entity { $entity } in { $target } does not exist in the source.
+cli-lint-markup-entity-missing = Entity { $entity } of the source is missing from { $target }.
+cli-lint-markup-href-changed = Link target "{ $href }" of the source is missing from { $target }.
+cli-lint-markup-tag-added = Tag <{ $tag }> in { $target } does not exist in the source.
+cli-lint-markup-tag-missing = Tag <{ $tag }> of the source is missing from { $target }.
+cli-lint-markup-tag-unbalanced = Tag <{ $tag }> is not properly closed in { $target }.
+cli-lint-max-length-exceeded = Length of { $target } is { $length } { $unit }, over the limit of { $limit }.
+cli-lint-max-length-file = Tab separated file of maximum lengths, one "context, message id or source, limit" per line.
+cli-lint-max-length-invalid-line = Invalid maximum length at line { $line }: "{ $content }".
+cli-lint-message-origin = context: { $context }, source: "{ $source }"
+cli-lint-output = If specified, will produce output in a file at designated location instead of stdout.
+cli-lint-placeholder-duplicated = Placeholder { $placeholder } is used more often in { $target } than in the source.
+cli-lint-placeholder-missing = Placeholder { $placeholder } is missing from { $target }.
+cli-lint-placeholder-out-of-range = Placeholder { $placeholder } in { $target } is beyond the highest source placeholder { $highest }.
+cli-lint-placeholder-unexpected = Placeholder { $placeholder } in { $target } does not exist in the source.
+cli-lint-problems-found = Found { $count } error(s) in "{ $file }".
+cli-lint-severity = Overrides the severity (error, warning or off) of lint rules, e.g. "capitalization=off".
+cli-lint-severity-invalid = Invalid value "{ $value }", expected "rule=severity" where severity is error, warning or off.
+cli-lint-severity-unknown-rule = Unknown lint rule "{ $rule }". Known rules: { $rules }.
+cli-lint-skip-accelerators = Languages for which keyboard accelerators are not checked, e.g. "ja" or "zh_CN".
+cli-lint-target-numerus = numerus form { $index }
+cli-lint-target-translation = the translation
+cli-lint-trailing-whitespace = Trailing whitespace of { $target } differs from the source.
+cli-merge-base = Common ancestor of both files. When set, performs a three-way merge taking each change from the side which made it.
+cli-merge-base-single-input = A three-way merge takes a single file to include changes from.
+cli-merge-conflict-note = Merge conflict: the other branch translated this message as "{ $translation }".
+cli-merge-conflict-report = File listing the messages whose translation was changed differently on both sides of a three-way merge.
+cli-merge-conflict-report-line = Context "{ $context }", source "{ $source }": "{ $left }" (left) conflicts with "{ $right }" (right).
+cli-merge-conflicts = { $count } message(s) have conflicting translations, marked unfinished for review.
+cli-merge-desc = Merges translation files contexts and messages into a single output.
+cli-merge-driver-base = Common ancestor of both versions of the file (%O).
+cli-merge-driver-conflicts = { $count } message(s) of "{ $file }" have conflicting translations, marked unfinished for review.
+cli-merge-driver-desc = Three-way merges TS files as a git merge driver: merge-driver %O %A %B.
+cli-merge-driver-ours = Current version of the file (%A), which receives the result of the merge.
+cli-merge-driver-theirs = Version of the file from the other branch (%B).
+cli-merge-dry-run = Prints the changes the merge would make to the left file instead of the merged file.
+cli-merge-fuzzy-threshold = Similarity, from 0 to 1, above which a new source takes the translation of the closest source which is no longer in the right file, e.g. 0.8. The translation is marked unfinished and the previous source kept as old source.
+cli-merge-fuzzy-threshold-invalid = Invalid similarity "{ $value }", expected a number from 0 to 1.
 cli-merge-input-left = File to receive the merge.
-cli-merge-input-right = File to include changes from.
+cli-merge-input-right = Files to include changes from, in order: each file takes precedence over the previous ones.
+cli-merge-no-obsolete = Removes the vanished and obsolete messages of an update, and the contexts left empty.
 cli-merge-keep-translation = When set, do not update translation in left file. This is used for adding new message nodes or context nodes.
 cli-merge-output = If specified, will produce output in a file at designated location instead of stdout.
+cli-merge-provenance-line = Context "{ $context }", source "{ $source }": { $file }
+cli-merge-provenance-report = File listing the input from which each translation of the merged file comes.
+cli-merge-provenance-summary = { $count } translation(s) from "{ $file }".
+cli-merge-report-change = Context "{ $context }", source "{ $source }": "{ $old }" → "{ $new }"
+cli-merge-report-comments-updated = Comments updated ({ $count }):
+cli-merge-report-contexts-added = Contexts added ({ $count }):
+cli-merge-report-format = Format of the report of --dry-run: human readable text or JSON.
+cli-merge-report-locations-changed = Locations changed ({ $count }):
+cli-merge-report-message = Context "{ $context }", source "{ $source }"
+cli-merge-report-messages-added = Messages added ({ $count }):
+cli-merge-report-messages-removed = Messages removed ({ $count }):
+cli-merge-report-messages-restored = Messages restored ({ $count }):
+cli-merge-report-messages-vanished = Messages vanished ({ $count }):
+cli-merge-report-no-changes = The merge makes no changes.
+cli-merge-report-sources-updated = Sources updated ({ $count }):
+cli-merge-report-translations-overwritten = Translations overwritten ({ $count }):
+cli-merge-strategy = How messages of both files are identified as the same message: by id, else source ("id-or-source", the historical behavior), by id only ("id"), by source and disambiguation comment in any context ("source-comment", as Qt does) or within the same context ("source-comment-context").
+cli-merge-update = Takes the right file as the complete set of current sources, e.g. freshly extracted by lupdate. Translations are kept, left messages missing from it are marked vanished (finished) or obsolete (unfinished), and messages which reappear get their status back.
+cli-merge-update-single-input = An update takes a single file to include changes from.
 cli-release-desc = Release a TS translation file as a binary QM translation file.
-cli-release-input = File to release as QM file.
+cli-release-input = File to release as QM file, or "@group" for a group of a single file of the project configuration.
 cli-release-keep-comments = If specified, will keep the comments in the output QM file.
 cli-release-output = If specified, will produce output in a file at designated location instead of stdout.
 cli-shell-completion-desc = Prints a shell completion for supported shells.
@@ -25,27 +136,85 @@ cli-shell-completion-error-open = Could not open file for writing. System error:
 cli-shell-completion-error-write-privilege = Could not write to destination path. Are privileges required ? System error: "{ $error }".
 cli-shell-completion-error-write-to-file = Could not write the completion completely to "{ $file }". Please validate output file.
 cli-sort-desc = Sorts the input translation file by context, then by messages.
-cli-sort-input = File path to sort translations from.
+cli-sort-input = File path to sort translations from, or "@group" for a group of a single file of the project configuration.
+cli-sort-order = Order of the messages within each context: "location" (default, by first location) or "source" (by source text).
 cli-sort-output = If specified, will produce output in a file at designated location instead of stdout.
+cli-spell-check-desc = Checks the spelling of translations with local Hunspell dictionaries.
+cli-spell-check-dictionary-dir = Directories containing Hunspell dictionaries (e.g. "de_DE.aff" and "de_DE.dic"), searched in order.
+cli-spell-check-format = Format in which misspellings are reported: human readable text, JSON, SARIF 2.1.0 or GitHub Actions annotations.
+cli-spell-check-input = File path to check the spelling of translations from, or "@group" for a group of files of the project configuration.
+cli-spell-check-language = Language of the dictionary to use. Defaults to the language of the input file.
+cli-spell-check-misspelled = Misspelled words in { $target }: { $words }.
+cli-spell-check-no-dictionary = No Hunspell dictionary found for language "{ $language }".
+cli-spell-check-no-language = The file "{ $file }" has no language, specify one with --language.
+cli-spell-check-output = If specified, will produce output in a file at designated location instead of stdout.
+cli-spell-check-problems-found = Found { $count } misspelled translation(s) in "{ $file }".
+cli-spell-check-word-list = Files of project words to accept, one per line. Lines starting with "#" are comments.
+cli-stat-characters = Characters
+cli-stat-complete = Complete
+cli-stat-context = Context
+cli-stat-contexts = Prints the counts and the completion percentage of each context, after the summary.
+cli-stat-dashboard-options = --verbose, --contexts and --sort-contexts apply to a single file, not to the summary of several files.
+cli-stat-dashboard-title = Translation completion
 cli-stat-desc = Calculates some statistics about the input file.
 cli-stat-detailed-report = Detailed report:
+cli-stat-fail-under = Fails when a file has a lower percentage of finished messages, among the messages which are neither obsolete nor vanished, e.g. 95.
+cli-stat-fail-under-invalid = Invalid percentage "{ $value }", expected a number from 0 to 100.
+cli-stat-fail-under-violation = { $file } ({ $language }): { $finished } of { $total } messages finished ({ $percent }%), under { $threshold }%
 cli-stat-filepath-header = File:
 cli-stat-file-summary = File summary
-cli-stat-files = Prints the detailed list of files, before the summary.
+cli-stat-files = Files
+cli-stat-format = Format of the statistics: human readable text, JSON, CSV (one "scope,name,metric,value" row per statistic), Markdown tables, or a self-contained HTML page of the completion by language and module. Several files are summarized as a matrix of completion by language and module.
+cli-stat-language = Language
+cli-stat-max-unfinished = Fails when a file has more unfinished or missing translations.
+cli-stat-max-unfinished-violation = { $file } ({ $language }): { $count } unfinished translations, over { $max }
+cli-stat-missing = Missing
 cli-stat-messages-without-context = Messages without context
+cli-stat-no-language = (no language)
+cli-stat-no-vanished = Fails when a file has vanished translations.
+cli-stat-no-vanished-violation = { $file } ({ $language }): { $count } vanished translations
+cli-stat-overall = Overall
+cli-stat-source-size = Source text
+cli-stat-thresholds-failed = { $count } threshold(s) not met:
+cli-stat-total = Total
 cli-stat-unique-translations = Unique translations
 cli-stat-translations-refs = Translations refs
 cli-stat-type-translations = { $ttype } translations
-cli-stat-input = File path to get stats on.
+cli-stat-input = File paths to get stats on, directories of TS files, or @group for a group of the project configuration. Several files are summarized by language and module.
 cli-stat-output = If specified, will produce output in a file at designated location instead of stdout.
-cli-stat-verbose = Files
+cli-stat-sort-contexts = Order of the contexts: as in the file, or the least complete first.
+cli-stat-verbose = Prints the detailed list of files, before the summary.
+cli-stat-words = Words
+cli-stat-words-left = Words left
+cli-status-changes-context = Context
+cli-status-changes-desc = Reports the translation work done between two versions of a translation file: messages newly finished or unfinished, edited, gone obsolete or removed, with the source words of the new work.
+cli-status-changes-edited = Translations edited ({ $count }):
+cli-status-changes-edited-header = Edited
+cli-status-changes-finished-header = Finished
+cli-status-changes-format = Format of the report: human readable text or JSON.
+cli-status-changes-new = Current version of the file, or "@group" for a group of a single file of the project configuration.
+cli-status-changes-newly-finished = Newly finished ({ $count }):
+cli-status-changes-newly-unfinished = Newly unfinished ({ $count }):
+cli-status-changes-none = No status changes.
+cli-status-changes-obsoleted = Went obsolete or vanished ({ $count }):
+cli-status-changes-obsoleted-header = Obsolete
+cli-status-changes-old = Previous version of the file, or "@group" for a group of a single file of the project configuration.
+cli-status-changes-output = If specified, will produce output in a file at designated location instead of stdout.
+cli-status-changes-removed = Removed from the file ({ $count }):
+cli-status-changes-removed-header = Removed
+cli-status-changes-total = Total
+cli-status-changes-unfinished-header = Unfinished
+cli-status-changes-words = Words
 cli-strip-desc = Strips the input translation file from translation determined by filter.
-cli-strip-input = File to strip translations from.
+cli-strip-input = File to strip translations from, or "@group" for a group of a single file of the project configuration.
 cli-strip-output = If specified, output file path.
 cli-strip-translation-type = Translation types to strip from the file
 cli-version = Prints the version of this tool.
+error-config-group-not-single = Group "{ $group }" must have a single file for this command.
+error-config-unknown-group = Unknown group "{ $group }" in the project configuration.
 error-open-or-parse = Could not open or parse input file "{ $file }". Reason: { $error }.
 error-ts-file-parse = Could not parse input file "{ $file }". Reason: { $error }.
 error-write-output-open = Error occured while opening output file { $output_path }. Reason: { $error }.
 error-write-output = Error occured while writing to output file { $output_path }. Reason: { $error }.
 error-ts-write-serialize = Problem occured while serializing output translation file. Reason: { $error }.
+error-json-serialize = Problem occured while serializing output to JSON. Reason: { $error }.
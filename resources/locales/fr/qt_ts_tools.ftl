@@ -1,6 +1,42 @@
 cli-about = Petit utilitaire de ligne de commands pour manipuler les fichiers de traduction TS de Qt.
+cli-config = Fichier de configuration du projet à utiliser au lieu du "qt-ts-tools.toml" trouvé dans le répertoire courant ou ses parents.
+cli-consistency-desc = Liste les sources traduites différemment selon les contextes, et optionnellement les remplace par la traduction majoritaire.
+cli-consistency-fix = Produit le fichier de traduction avec les traductions divergentes remplacées par la traduction majoritaire et marquées non terminées pour révision.
+cli-consistency-found = { $count } source(s) traduite(s) de façon incohérente dans "{ $file }".
+cli-consistency-input = Chemin d'accès du fichier de traduction à vérifier, ou "@groupe" pour un groupe de fichiers de la configuration du projet.
+cli-consistency-no-majority = { $count } source(s) de "{ $file }" n'ont pas de traduction majoritaire et n'ont pas été modifiées.
+cli-consistency-output = Si spécifié, chemin d'accès du fichier de sortie.
+cli-consistency-source = "{ $source }" a { $count } traductions différentes :
+cli-consistency-variant = "{ $translation }" utilisée { $count } fois dans { $contexts }
+cli-diff-change = { $field } : « { $old } » → « { $new } »
+cli-diff-contexts-added = Contextes ajoutés ({ $count }) :
+cli-diff-contexts-removed = Contextes supprimés ({ $count }) :
+cli-diff-contexts-reordered = L'ordre des contextes a changé.
+cli-diff-desc = Compare deux fichiers de traductions par contexte et message, en ignorant les changements d'emplacement et d'ordre.
+cli-diff-differences-found = { $count } différence(s) trouvée(s) entre les deux fichiers.
+cli-diff-format = Format du rapport : texte lisible ou JSON.
+cli-diff-locations = Signale aussi les messages dont les emplacements ont changé.
+cli-diff-message = Contexte « { $context } », source « { $source } »
+cli-diff-messages-added = Messages ajoutés ({ $count }) :
+cli-diff-messages-changed = Messages modifiés ({ $count }) :
+cli-diff-messages-removed = Messages supprimés ({ $count }) :
+cli-diff-messages-reordered = Contextes dont les messages ont été réordonnés ({ $count }) :
+cli-diff-new = Version actuelle du fichier, ou "@groupe" pour un groupe d'un seul fichier de la configuration du projet.
+cli-diff-no-differences = Aucune différence.
+cli-diff-old = Version précédente du fichier, ou "@groupe" pour un groupe d'un seul fichier de la configuration du projet.
+cli-diff-order = Signale aussi les contextes et messages dont l'ordre a changé.
+cli-diff-output = Si spécifié, chemin d'accès du fichier de sortie.
+cli-diff-strategy = Façon dont les messages des deux versions sont identifiés comme étant le même message dans un contexte : « source-comment-context » (par défaut, comme Qt), « id », « source-comment » ou « id-or-source ».
+cli-duplicates-conflict = Traductions en conflit : { $translations }
+cli-duplicates-conflicts-remaining = Les doublons de "{ $file }" ont été fusionnés, mais { $count } message(s) avaient des traductions en conflit à réviser.
+cli-duplicates-desc = Liste les messages apparaissant plus d'une fois dans un contexte, et optionnellement les fusionne.
+cli-duplicates-fix = Produit le fichier de traduction avec les doublons fusionnés : les emplacements sont combinés et la traduction terminée est conservée.
+cli-duplicates-found = { $count } message(s) en double dans "{ $file }".
+cli-duplicates-input = Chemin d'accès du fichier de traduction dans lequel chercher les doublons, ou "@groupe" pour un groupe de fichiers de la configuration du projet.
+cli-duplicates-message = "{ $source }" apparaît { $count } fois ({ $locations }).
+cli-duplicates-output = Si spécifié, chemin d'accès du fichier de sortie.
 cli-extract-desc = Extrait les messages et contextes correspondants au type de tranduction spécifié.
-cli-extract-input = Chemin d'accès du fichier de traduction à extraire.
+cli-extract-input = Chemin d'accès du fichier de traduction à extraire, ou "@groupe" pour un groupe d'un seul fichier de la configuration du projet.
 cli-extract-translation-type = Liste des types de traductions à extraire.
 cli-extract-output = Si spécifié, chemin d'accès du fichier de sortie.
 cli-headers-commands = Commandes
@@ -8,13 +44,88 @@ cli-headers-options = Options
 cli-headers-usage = Utilisation
 cli-headers-arguments = Arguments
 cli-help = Affiche l'aide.
-cli-merge-desc = Fusionne les contextes et message des deux fichiers de traductions spécifié en un seul.
+cli-lint-accelerator-duplicated = Le raccourci "{ $mnemonic }" est déjà utilisé par "{ $other }" dans le même contexte.
+cli-lint-accelerator-extra = Le raccourci clavier (&) dans { $target } n'existe pas dans la source.
+cli-lint-accelerator-missing = Le raccourci clavier (&) de la source est absent de { $target }.
+cli-lint-capitalization = Début en minuscule dans { $target } alors que la source commence par une majuscule.
+cli-lint-desc = Vérifie les traductions du fichier d'entrée pour des erreurs communes.
+cli-lint-double-spaces = Espaces doublées dans { $target }.
+cli-lint-ending-punctuation-added = La ponctuation finale "{ $punctuation }" dans { $target } n'existe pas dans la source.
+cli-lint-ending-punctuation-changed = La ponctuation finale "{ $punctuation }" dans { $target } diffère de "{ $expected }" dans la source.
+cli-lint-ending-punctuation-missing = La ponctuation finale "{ $punctuation }" de la source est absente de { $target }.
+cli-lint-format = Format du rapport des problèmes : texte lisible, JSON, SARIF 2.1.0 ou annotations GitHub Actions.
+cli-lint-glossary = Glossaires des termes à respecter: livres de phrases Qt (.qph) ou fichiers "source, cible" séparés par des tabulations. Une entête "# language: de" restreint un fichier séparé par des tabulations à une langue.
+cli-lint-glossary-match = Comparaison des termes du glossaire : "word" pour des mots entiers, "prefix" pour accepter toute terminaison (ex. pluriels, mais aussi des mots plus longs).
+cli-lint-glossary-term = Le terme du glossaire "{ $term }" devrait être traduit par "{ $expected }" dans { $target }.
+cli-lint-input = Chemin d'accès du fichier de traduction à vérifier, ou "@groupe" pour un groupe de fichiers de la configuration du projet.
+cli-lint-leading-whitespace = Les espaces au début de { $target } diffèrent de la source.
+cli-lint-length-unit = Unité de mesure de la longueur des traductions.
+cli-lint-length-unit-chars = caractères
+cli-lint-length-unit-utf16 = unités de code UTF-16
+cli-lint-markup-entity-added = L'entité { $entity } dans { $target } n'existe pas dans la source.
+cli-lint-markup-entity-missing = L'entité { $entity } de la source est absente de { $target }.
+cli-lint-markup-href-changed = La cible du lien "{ $href }" de la source est absente de { $target }.
+cli-lint-markup-tag-added = La balise <{ $tag }> dans { $target } n'existe pas dans la source.
+cli-lint-markup-tag-missing = La balise <{ $tag }> de la source est absente de { $target }.
+cli-lint-markup-tag-unbalanced = La balise <{ $tag }> n'est pas correctement fermée dans { $target }.
+cli-lint-max-length-exceeded = La longueur de { $target } est de { $length } { $unit }, au-delà de la limite de { $limit }.
+cli-lint-max-length-file = Fichier de longueurs maximales séparé par des tabulations, un "contexte, identifiant ou source du message, limite" par ligne.
+cli-lint-max-length-invalid-line = Longueur maximale invalide à la ligne { $line }: "{ $content }".
+cli-lint-message-origin = contexte: { $context }, source: "{ $source }"
+cli-lint-output = Si spécifié, chemin d'accès du fichier de sortie.
+cli-lint-placeholder-duplicated = Le paramètre { $placeholder } est utilisé plus souvent dans { $target } que dans la source.
+cli-lint-placeholder-missing = Le paramètre { $placeholder } est absent de { $target }.
+cli-lint-placeholder-out-of-range = Le paramètre { $placeholder } dans { $target } dépasse le plus grand paramètre de la source { $highest }.
+cli-lint-placeholder-unexpected = Le paramètre { $placeholder } dans { $target } n'existe pas dans la source.
+cli-lint-problems-found = { $count } erreur(s) trouvée(s) dans "{ $file }".
+cli-lint-severity = Remplace la sévérité (error, warning ou off) des règles de vérification, par ex. "capitalization=off".
+cli-lint-severity-invalid = Valeur "{ $value }" invalide, "règle=sévérité" attendu où la sévérité est error, warning ou off.
+cli-lint-severity-unknown-rule = Règle de vérification "{ $rule }" inconnue. Règles connues: { $rules }.
+cli-lint-skip-accelerators = Langues pour lesquelles les raccourcis clavier ne sont pas vérifiés, par ex. "ja" ou "zh_CN".
+cli-lint-target-numerus = la forme plurielle { $index }
+cli-lint-target-translation = la traduction
+cli-lint-trailing-whitespace = Les espaces à la fin de { $target } diffèrent de la source.
+cli-merge-base = Ancêtre commun des deux fichiers. Si spécifié, effectue une fusion à trois voies prenant chaque modification du côté qui l'a faite.
+cli-merge-base-single-input = Un merge à trois voies ne prend qu'un seul fichier duquel inclure les changements.
+cli-merge-conflict-note = Conflit de fusion : l'autre branche traduit ce message par "{ $translation }".
+cli-merge-conflict-report = Fichier listant les messages dont la traduction a été modifiée différemment des deux côtés d'une fusion à trois voies.
+cli-merge-conflict-report-line = Contexte "{ $context }", source "{ $source }" : "{ $left }" (gauche) est en conflit avec "{ $right }" (droite).
+cli-merge-conflicts = { $count } message(s) ont des traductions en conflit, marquées non terminées pour révision.
+cli-merge-desc = Fusionne les contextes et messages des fichiers de traductions spécifiés en un seul.
+cli-merge-driver-base = Ancêtre commun des deux versions du fichier (%O).
+cli-merge-driver-conflicts = { $count } message(s) de "{ $file }" ont des traductions en conflit, marquées non terminées pour révision.
+cli-merge-driver-desc = Fusionne à trois voies des fichiers TS en tant que pilote de fusion git : merge-driver %O %A %B.
+cli-merge-driver-ours = Version courante du fichier (%A), qui reçoit le résultat de la fusion.
+cli-merge-driver-theirs = Version du fichier provenant de l'autre branche (%B).
+cli-merge-dry-run = Affiche les changements que le merge apporterait au fichier de gauche au lieu du fichier fusionné.
+cli-merge-fuzzy-threshold = Similarité, de 0 à 1, à partir de laquelle une nouvelle source reprend la traduction de la source la plus proche qui n'est plus dans le fichier de droite, par ex. 0.8. La traduction est marquée non terminée et la source précédente conservée comme ancienne source.
+cli-merge-fuzzy-threshold-invalid = Similarité « { $value } » invalide, un nombre de 0 à 1 est attendu.
 cli-merge-input-left = Fichier qui reçoit les changements.
-cli-merge-input-right = Fichier qui possède les changements.
+cli-merge-input-right = Fichiers qui possèdent les changements, dans l'ordre : chaque fichier a priorité sur les précédents.
+cli-merge-no-obsolete = Supprime les messages disparus et obsolètes d'une mise à jour, ainsi que les contextes laissés vides.
 cli-merge-keep-translation = Lorsque spécifié, le merge ne met pas à jour les traductions dans le fichier de gauche. Utilisé pour ajouté les nouveaux noeuds "message" et "context".
 cli-merge-output = Si spécificé, chemin d'accès du fichier de sortie.
+cli-merge-provenance-line = Contexte « { $context } », source « { $source } » : { $file }
+cli-merge-provenance-report = Fichier listant l'entrée de laquelle provient chaque traduction du fichier fusionné.
+cli-merge-provenance-summary = { $count } traduction(s) de « { $file } ».
+cli-merge-report-change = Contexte « { $context } », source « { $source } » : « { $old } » → « { $new } »
+cli-merge-report-comments-updated = Commentaires mis à jour ({ $count }) :
+cli-merge-report-contexts-added = Contextes ajoutés ({ $count }) :
+cli-merge-report-format = Format du rapport de --dry-run : texte lisible ou JSON.
+cli-merge-report-locations-changed = Emplacements modifiés ({ $count }) :
+cli-merge-report-message = Contexte « { $context } », source « { $source } »
+cli-merge-report-messages-added = Messages ajoutés ({ $count }) :
+cli-merge-report-messages-removed = Messages supprimés ({ $count }) :
+cli-merge-report-messages-restored = Messages restaurés ({ $count }) :
+cli-merge-report-messages-vanished = Messages disparus ({ $count }) :
+cli-merge-report-no-changes = Le merge n'apporte aucun changement.
+cli-merge-report-sources-updated = Sources mises à jour ({ $count }) :
+cli-merge-report-translations-overwritten = Traductions écrasées ({ $count }) :
+cli-merge-strategy = Façon dont les messages des deux fichiers sont identifiés comme étant le même message : par identifiant, sinon par source (« id-or-source », le comportement historique), par identifiant seulement (« id »), par source et commentaire de désambiguïsation dans n'importe quel contexte (« source-comment », comme Qt) ou dans le même contexte (« source-comment-context »).
+cli-merge-update = Considère le fichier de droite comme l'ensemble complet des sources actuelles, par ex. fraîchement extraites par lupdate. Les traductions sont conservées, les messages de gauche absents sont marqués disparus (terminés) ou obsolètes (non terminés), et les messages qui réapparaissent retrouvent leur statut.
+cli-merge-update-single-input = Une mise à jour ne prend qu'un seul fichier duquel inclure les changements.
 cli-release-desc = Publie un fichier de traduction TS en fichier binaire QM. 
-cli-release-input = Fichier à publier en fichier QM.
+cli-release-input = Fichier à publier en fichier QM, ou "@groupe" pour un groupe d'un seul fichier de la configuration du projet.
 cli-release-keep-comments = Lorsque spécifié, le fichier QM sera publié avec les commentaires conservés.
 cli-release-output = Lorsque spécifié, le fichier QM sera publié à ce chemin d'accès.
 cli-shell-completion-desc = Affiche le script d'auto-complétion pour un shell choisi.
@@ -25,27 +136,85 @@ cli-shell-completion-error-open = Impossible d'ouvrir le fichier en écriture. E
 cli-shell-completion-error-write-privilege = Impossible d'écrire la destination. Des privilèges sont-ils requis ? Erreur système: "{ $error }".
 cli-shell-completion-error-write-to-file = Ne peux écrire complètement le fichier "{ $file }". Veuillez valider le fichier de sortie.
 cli-sort-desc = Trie le fichier de traduction par contextes, puis messages.
-cli-sort-input = Chemin d'accès du fichier de traduction à trier.
+cli-sort-input = Chemin d'accès du fichier de traduction à trier, ou "@groupe" pour un groupe d'un seul fichier de la configuration du projet.
+cli-sort-order = Ordre des messages dans chaque contexte : « location » (par défaut, par premier emplacement) ou « source » (par texte source).
 cli-sort-output = Si spécifié, chemin d'accès du fichier de sortie.
+cli-spell-check-desc = Vérifie l'orthographe des traductions avec des dictionnaires Hunspell locaux.
+cli-spell-check-dictionary-dir = Répertoires contenant les dictionnaires Hunspell (ex. "de_DE.aff" et "de_DE.dic"), parcourus dans l'ordre.
+cli-spell-check-format = Format du rapport des fautes : texte lisible, JSON, SARIF 2.1.0 ou annotations GitHub Actions.
+cli-spell-check-input = Chemin d'accès du fichier de traduction à vérifier, ou "@groupe" pour un groupe de fichiers de la configuration du projet.
+cli-spell-check-language = Langue du dictionnaire à utiliser. Par défaut, la langue du fichier d'entrée.
+cli-spell-check-misspelled = Mots mal orthographiés dans { $target } : { $words }.
+cli-spell-check-no-dictionary = Aucun dictionnaire Hunspell trouvé pour la langue "{ $language }".
+cli-spell-check-no-language = Le fichier "{ $file }" n'a pas de langue, spécifiez-en une avec --language.
+cli-spell-check-output = Si spécifié, chemin d'accès du fichier de sortie.
+cli-spell-check-problems-found = { $count } traduction(s) mal orthographiée(s) dans "{ $file }".
+cli-spell-check-word-list = Fichiers de mots du projet à accepter, un par ligne. Les lignes commençant par "#" sont des commentaires.
+cli-stat-characters = Caractères
+cli-stat-complete = Complété
+cli-stat-context = Contexte
+cli-stat-contexts = Affiche les décomptes et le pourcentage de complétion de chaque contexte, après le sommaire.
+cli-stat-dashboard-options = --verbose, --contexts et --sort-contexts s'appliquent à un seul fichier, pas au résumé de plusieurs fichiers.
+cli-stat-dashboard-title = Avancement des traductions
 cli-stat-desc = Produit le sommaire statistique d'un fichier.
 cli-stat-detailed-report = Rapport détaillé:
+cli-stat-fail-under = Échoue lorsqu'un fichier a un pourcentage inférieur de messages terminés, parmi les messages ni obsolètes ni disparus, ex. 95.
+cli-stat-fail-under-invalid = Pourcentage « { $value } » invalide, un nombre de 0 à 100 est attendu.
+cli-stat-fail-under-violation = { $file } ({ $language }) : { $finished } messages terminés sur { $total } ({ $percent } %), sous { $threshold } %
 cli-stat-filepath-header = Fichier:
 cli-stat-file-summary = Résumé du fichier
 cli-stat-files = Fichiers
+cli-stat-format = Format des statistiques : texte lisible, JSON, CSV (une ligne « scope,name,metric,value » par statistique), tableaux Markdown, ou page HTML autonome de l'avancement par langue et module. Plusieurs fichiers sont résumés par une matrice de l'avancement par langue et module.
+cli-stat-language = Langue
+cli-stat-max-unfinished = Échoue lorsqu'un fichier a plus de traductions non terminées ou manquantes.
+cli-stat-max-unfinished-violation = { $file } ({ $language }) : { $count } traductions non terminées, au-delà de { $max }
+cli-stat-missing = Manquant
 cli-stat-messages-without-context = Messages sans contexte
+cli-stat-no-language = (sans langue)
+cli-stat-no-vanished = Échoue lorsqu'un fichier a des traductions disparues.
+cli-stat-no-vanished-violation = { $file } ({ $language }) : { $count } traductions disparues
+cli-stat-overall = Global
+cli-stat-source-size = Texte source
+cli-stat-thresholds-failed = { $count } seuil(s) non respecté(s) :
+cli-stat-total = Total
 cli-stat-unique-translations = Traduction unique
 cli-stat-translations-refs = Traduction réfs.
 cli-stat-type-translations = Traduction "{ $ttype }"
-cli-stat-input = Chemin du fichier pour obtenir les statistiques.
+cli-stat-input = Chemins des fichiers pour obtenir les statistiques, répertoires de fichiers TS, ou @groupe pour un groupe de la configuration du projet. Plusieurs fichiers sont résumés par langue et module.
 cli-stat-output = Lorsque spécifié, produit le résultat dans le fichier au chemin spécifié.
+cli-stat-sort-contexts = Ordre des contextes : celui du fichier, ou les moins complets en premier.
 cli-stat-verbose = Affiche la liste détaillée des fichiers, préalable au sommaire
+cli-stat-words = Mots
+cli-stat-words-left = Mots restants
+cli-status-changes-context = Contexte
+cli-status-changes-desc = Rapporte le travail de traduction effectué entre deux versions d'un fichier de traductions : messages nouvellement terminés ou non terminés, modifiés, devenus obsolètes ou supprimés, avec les mots sources du nouveau travail.
+cli-status-changes-edited = Traductions modifiées ({ $count }) :
+cli-status-changes-edited-header = Modifiés
+cli-status-changes-finished-header = Terminés
+cli-status-changes-format = Format du rapport : texte lisible ou JSON.
+cli-status-changes-new = Version actuelle du fichier, ou "@groupe" pour un groupe d'un seul fichier de la configuration du projet.
+cli-status-changes-newly-finished = Nouvellement terminés ({ $count }) :
+cli-status-changes-newly-unfinished = Nouvellement non terminés ({ $count }) :
+cli-status-changes-none = Aucun changement de statut.
+cli-status-changes-obsoleted = Devenus obsolètes ou disparus ({ $count }) :
+cli-status-changes-obsoleted-header = Obsolètes
+cli-status-changes-old = Version précédente du fichier, ou "@groupe" pour un groupe d'un seul fichier de la configuration du projet.
+cli-status-changes-output = Si spécifié, chemin d'accès du fichier de sortie.
+cli-status-changes-removed = Supprimés du fichier ({ $count }) :
+cli-status-changes-removed-header = Supprimés
+cli-status-changes-total = Total
+cli-status-changes-unfinished-header = Non terminés
+cli-status-changes-words = Mots
 cli-strip-desc = Retire les traductions du fichier de traduction spécifié selon le filtre donné.
-cli-strip-input = Fichier à enlever les traductions
+cli-strip-input = Fichier à enlever les traductions, ou "@groupe" pour un groupe d'un seul fichier de la configuration du projet.
 cli-strip-output = Si spécifié, chemin d'accès du fichier de sortie.
 cli-strip-translation-type = Types de traductions à retirer du fichier d'entrée
 cli-version = Affiche la version de l'utilitaire.
+error-config-group-not-single = Le groupe "{ $group }" doit contenir un seul fichier pour cette commande.
+error-config-unknown-group = Groupe "{ $group }" inconnu dans la configuration du projet.
 error-open-or-parse = Échec de lecture ou décodage du fichier "{ $file }". Raison: { $error }.
 error-ts-file-parse = Échec de lecture du fichier "{ $file }". Raison: { $error }.
 error-write-output-open = Erreur lors de l'ouverture en écriture du fichier "{ $file }". Raison: { $error }.
 error-write-output = Erreur lors de l'écriture du fichier "{ $output_path }". Raison: { $error }.
 error-ts-write-serialize = Un problème est survenue lors de la sérialization du fichier. Raison: { $error }.
+error-json-serialize = Un problème est survenu lors de la sérialisation en JSON. Raison: { $error }.
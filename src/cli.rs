@@ -1,12 +1,20 @@
 use clap::{ArgAction, Parser, Subcommand};
 
+use crate::commands::consistency::{ConsistencyArgs, consistency_main};
+use crate::commands::diff::{DiffArgs, diff_main};
+use crate::commands::duplicates::{DuplicatesArgs, duplicates_main};
 use crate::commands::extract::{ExtractArgs, extract_main};
+use crate::commands::lint::{LintArgs, lint_main};
 use crate::commands::merge::{MergeArgs, merge_main};
+use crate::commands::merge_driver::{MergeDriverArgs, merge_driver_main};
 use crate::commands::release::{ReleaseArgs, release_main};
 use crate::commands::shell_completion::{ShellCompletionArgs, shell_completion_main};
 use crate::commands::sort::{SortArgs, sort_main};
+use crate::commands::spell_check::{SpellCheckArgs, spell_check_main};
 use crate::commands::stat::{StatArgs, stat_main};
+use crate::commands::status_changes::{StatusChangesArgs, status_changes_main};
 use crate::commands::strip::{StripArgs, strip_main};
+use crate::config::initialize_config;
 use crate::locale::tr;
 
 #[derive(Parser)]
@@ -23,22 +31,39 @@ pub struct Cli {
     pub help: Option<bool>,
     #[arg(short, long, short_alias = 'v', action = ArgAction::Version, help = tr!("cli-version"))]
     version: Option<bool>,
+    /// Project configuration file, instead of the `qt-ts-tools.toml` found in the working directory or its parents.
+    #[arg(long, global = true, help = tr!("cli-config"), help_heading = tr!("cli-headers-options"))]
+    config: Option<String>,
 }
 
 #[derive(Subcommand)]
 #[command(subcommand_help_heading = tr!("cli-headers-commands"),
     next_help_heading = tr!("cli-headers-options"))]
 enum Commands {
+    #[command(about = tr!("cli-consistency-desc"))]
+    Consistency(ConsistencyArgs),
+    #[command(about = tr!("cli-diff-desc"))]
+    Diff(DiffArgs),
+    #[command(about = tr!("cli-duplicates-desc"))]
+    Duplicates(DuplicatesArgs),
     #[command(about = tr!("cli-extract-desc"))]
     Extract(ExtractArgs),
+    #[command(about = tr!("cli-lint-desc"))]
+    Lint(LintArgs),
     #[command(about = tr!("cli-merge-desc"))]
     Merge(MergeArgs),
+    #[command(name = "merge-driver", about = tr!("cli-merge-driver-desc"))]
+    MergeDriver(MergeDriverArgs),
     #[command(about = tr!("cli-release-desc"))]
     Release(ReleaseArgs),
     #[command(about = tr!("cli-sort-desc"))]
     Sort(SortArgs),
+    #[command(name = "spell-check", about = tr!("cli-spell-check-desc"))]
+    SpellCheck(SpellCheckArgs),
     #[command(about = tr!("cli-stat-desc"))]
     Stat(StatArgs),
+    #[command(name = "status-changes", about = tr!("cli-status-changes-desc"))]
+    StatusChanges(StatusChangesArgs),
     #[command(about = tr!("cli-strip-desc"))]
     Strip(StripArgs),
     // Want to have shell-completion as the very last option displayed
@@ -49,12 +74,21 @@ enum Commands {
 pub fn get_cli_result() -> Result<(), String> {
     let cli = Cli::parse();
 
+    initialize_config(cli.config.as_deref())?;
+
     match cli.command {
+        Commands::Consistency(args) => consistency_main(&args),
+        Commands::Diff(args) => diff_main(&args),
+        Commands::Duplicates(args) => duplicates_main(&args),
         Commands::Extract(args) => extract_main(&args),
+        Commands::Lint(args) => lint_main(&args),
         Commands::Merge(args) => merge_main(&args),
+        Commands::MergeDriver(args) => merge_driver_main(&args),
         Commands::Release(args) => release_main(&args),
         Commands::Sort(args) => sort_main(&args),
+        Commands::SpellCheck(args) => spell_check_main(&args),
         Commands::Stat(args) => stat_main(&args),
+        Commands::StatusChanges(args) => status_changes_main(&args),
         Commands::Strip(args) => strip_main(&args),
         Commands::ShellCompletion(args) => shell_completion_main(&args),
     }
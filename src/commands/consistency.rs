@@ -0,0 +1,299 @@
+use std::collections::HashMap;
+
+use clap::{ArgAction, Args};
+use log::debug;
+
+use crate::commands::report::write_to_output;
+use crate::commands::text::translation_text;
+use crate::config::current_config;
+use crate::tr;
+use crate::ts;
+use crate::ts::{MessageNode, TSNode, TranslationNode, TranslationType};
+
+/// Lists sources translated differently across contexts, and optionally rewrites them to the majority translation.
+#[derive(Args)]
+#[command(disable_help_flag = true)]
+pub struct ConsistencyArgs {
+    /// File path to check the consistency of translations from, or `@group` for a group of the configuration.
+    #[arg(help = tr!("cli-consistency-input"), help_heading = tr!("cli-headers-arguments"))]
+    pub input_path: String,
+    /// When set, outputs the translation file with diverging translations rewritten to the majority one.
+    #[arg(long, action = ArgAction::SetTrue, help = tr!("cli-consistency-fix"), help_heading = tr!("cli-headers-options"))]
+    pub fix: bool,
+    /// If specified, will produce output in a file at designated location instead of stdout.
+    #[arg(short, long, help = tr!("cli-consistency-output"), help_heading = tr!("cli-headers-options"))]
+    pub output_path: Option<String>,
+    #[arg(short, long, action = ArgAction::Help, help = tr!("cli-help"), help_heading = tr!("cli-headers-options"))]
+    pub help: Option<bool>,
+}
+
+/// One of the translations used for a source.
+#[derive(Debug)]
+struct Variant {
+    /// Translation text, numerus forms being separated by ` | `.
+    text: String,
+    /// Translation node of the first message using it.
+    translation: TranslationNode,
+    count: usize,
+    /// Contexts using this translation, without repetition.
+    contexts: Vec<String>,
+}
+
+/// Source (along with its disambiguation comment) having several translations.
+#[derive(Debug)]
+struct InconsistentSource {
+    source: String,
+    comment: Option<String>,
+    /// Translations used for the source, the most used first.
+    variants: Vec<Variant>,
+}
+
+impl InconsistentSource {
+    /// Translation used strictly more often than any other, if any.
+    fn majority(&self) -> Option<&Variant> {
+        match self.variants.as_slice() {
+            [first, second, ..] if first.count > second.count => Some(first),
+            _ => None,
+        }
+    }
+}
+
+/// Reports sources that are translated differently across contexts. With `--fix`, outputs the file
+/// with these translations rewritten to the majority translation and marked unfinished for review.
+/// Returns an error when inconsistencies are found, or when some have no majority translation to apply.
+pub fn consistency_main(args: &ConsistencyArgs) -> Result<(), String> {
+    let config = current_config();
+
+    if args.fix {
+        let input_path = config.expand_single_input(&args.input_path)?;
+        let mut ts_node = ts::read_from_file(&input_path)?;
+        let inconsistencies = find_inconsistencies(&ts_node);
+
+        rewrite_to_majority(&mut ts_node, &inconsistencies);
+        ts::write_to_output(&args.output_path, &ts_node)?;
+
+        let unresolved: Vec<&InconsistentSource> = inconsistencies
+            .iter()
+            .filter(|inconsistency| inconsistency.majority().is_none())
+            .collect();
+
+        if unresolved.is_empty() {
+            return Ok(());
+        }
+
+        eprint!("{}", generate_report(&unresolved));
+        return Err(tr!(
+            "cli-consistency-no-majority",
+            count = unresolved.len(),
+            file = input_path.as_str()
+        ));
+    }
+
+    let input_paths = config.expand_input(&args.input_path)?;
+    let mut report = String::new();
+    let mut errors = vec![];
+
+    for input_path in &input_paths {
+        let ts_node = ts::read_from_file(input_path)?;
+        let inconsistencies = find_inconsistencies(&ts_node);
+
+        if inconsistencies.is_empty() {
+            continue;
+        }
+
+        // Files of a group are told apart by their path
+        if input_paths.len() > 1 {
+            report.push_str(&format!("{input_path}\n"));
+        }
+        report.push_str(&generate_report(
+            &inconsistencies.iter().collect::<Vec<_>>(),
+        ));
+        errors.push(tr!(
+            "cli-consistency-found",
+            count = inconsistencies.len(),
+            file = input_path.as_str()
+        ));
+    }
+
+    match &args.output_path {
+        None => print!("{report}"),
+        Some(output_path) => write_to_output(output_path, &report)?,
+    }
+
+    match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors.join("\n")),
+    }
+}
+
+/// Translation of a message, if it is translated and shipped.
+fn shipped_translation(message: &MessageNode) -> Option<(String, &TranslationNode)> {
+    let translation = message.translation.as_ref()?;
+
+    if matches!(
+        translation.translation_type,
+        Some(TranslationType::Obsolete) | Some(TranslationType::Vanished)
+    ) {
+        return None;
+    }
+
+    translation_text(message).map(|text| (text, translation))
+}
+
+/// Groups messages of every context by source and comment, a comment being a disambiguation
+/// which legitimately leads to different translations. Returns groups having diverging translations.
+fn find_inconsistencies(ts_node: &TSNode) -> Vec<InconsistentSource> {
+    let mut sources: Vec<InconsistentSource> = vec![];
+    let mut indexes: HashMap<(&str, Option<&str>), usize> = HashMap::new();
+
+    for context in &ts_node.contexts {
+        for message in &context.messages {
+            let Some(source) = message.source.as_deref() else {
+                continue;
+            };
+            let Some((text, translation)) = shipped_translation(message) else {
+                continue;
+            };
+
+            let index = *indexes
+                .entry((source, message.comment.as_deref()))
+                .or_insert_with(|| {
+                    sources.push(InconsistentSource {
+                        source: source.to_owned(),
+                        comment: message.comment.clone(),
+                        variants: vec![],
+                    });
+                    sources.len() - 1
+                });
+
+            let variants = &mut sources[index].variants;
+            match variants.iter_mut().find(|variant| variant.text == text) {
+                Some(variant) => {
+                    variant.count += 1;
+                    if !variant.contexts.contains(&context.name) {
+                        variant.contexts.push(context.name.clone());
+                    }
+                }
+                None => variants.push(Variant {
+                    text,
+                    translation: translation.clone(),
+                    count: 1,
+                    contexts: vec![context.name.clone()],
+                }),
+            }
+        }
+    }
+
+    sources.retain(|source| source.variants.len() > 1);
+    sources.iter_mut().for_each(|source| {
+        source
+            .variants
+            .sort_by_key(|variant| std::cmp::Reverse(variant.count))
+    });
+
+    debug!("Found {} inconsistently translated sources", sources.len());
+
+    sources
+}
+
+/// Rewrites diverging translations to the majority translation, marking them unfinished.
+/// Sources without a majority translation are left untouched.
+fn rewrite_to_majority(ts_node: &mut TSNode, inconsistencies: &[InconsistentSource]) {
+    let majorities: HashMap<(&str, Option<&str>), &Variant> = inconsistencies
+        .iter()
+        .filter_map(|inconsistency| {
+            inconsistency.majority().map(|majority| {
+                (
+                    (
+                        inconsistency.source.as_str(),
+                        inconsistency.comment.as_deref(),
+                    ),
+                    majority,
+                )
+            })
+        })
+        .collect();
+
+    for message in ts_node
+        .contexts
+        .iter_mut()
+        .flat_map(|context| context.messages.iter_mut())
+    {
+        let Some(source) = message.source.as_deref() else {
+            continue;
+        };
+        let Some(majority) = majorities.get(&(source, message.comment.as_deref())) else {
+            continue;
+        };
+
+        if shipped_translation(message).is_some_and(|(text, _)| text != majority.text) {
+            debug!("Rewriting translation of {source:?} to {:?}", majority.text);
+
+            let mut translation = majority.translation.clone();
+            translation.translation_type = Some(TranslationType::Unfinished);
+            message.translation = Some(translation);
+        }
+    }
+}
+
+/// Generates the report of inconsistently translated sources, listing the contexts using each translation.
+fn generate_report(inconsistencies: &[&InconsistentSource]) -> String {
+    let mut report = String::new();
+
+    for inconsistency in inconsistencies {
+        report.push_str(&format!(
+            "{}\n",
+            tr!(
+                "cli-consistency-source",
+                source = inconsistency.source.as_str(),
+                count = inconsistency.variants.len()
+            )
+        ));
+
+        for variant in &inconsistency.variants {
+            report.push_str(&format!(
+                "    {}\n",
+                tr!(
+                    "cli-consistency-variant",
+                    translation = variant.text.as_str(),
+                    count = variant.count,
+                    contexts = variant.contexts.join(", ")
+                )
+            ));
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod consistency_test {
+    use crate::commands::test_utils::{load_test_file, node_to_formatted_string, read_test_file};
+
+    use super::*;
+
+    #[test]
+    fn test_consistency_report() {
+        let data = load_test_file("example_consistency.xml");
+
+        let inconsistencies = find_inconsistencies(&data);
+        let report = generate_report(&inconsistencies.iter().collect::<Vec<_>>());
+
+        assert_eq!(
+            report,
+            "\"Cancel\" has 2 different translations:\n    \"Abbrechen\" used 2 time(s) in Dialog, Wizard\n    \"Annullieren\" used 1 time(s) in Settings\n\"Close\" has 2 different translations:\n    \"Schließen\" used 1 time(s) in Dialog\n    \"Beenden\" used 1 time(s) in Wizard\n"
+        );
+        assert!(inconsistencies[1].majority().is_none());
+    }
+
+    #[test]
+    fn test_rewrite_to_majority() {
+        let expected = read_test_file("example_consistency_fixed.xml");
+        let mut data = load_test_file("example_consistency.xml");
+
+        let inconsistencies = find_inconsistencies(&data);
+        rewrite_to_majority(&mut data, &inconsistencies);
+
+        assert_eq!(expected, node_to_formatted_string(&data));
+    }
+}
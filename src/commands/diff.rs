@@ -0,0 +1,497 @@
+use clap::{ArgAction, Args};
+use serde::Serialize;
+
+use crate::commands::report::write_to_output;
+use crate::commands::report::{MessageRef, to_json};
+use crate::commands::text::translation_text;
+use crate::config::{Config, current_config};
+use crate::options::{MatchStrategy, ReportFormat};
+use crate::tr;
+use crate::ts;
+use crate::ts::{MessageNode, TSNode};
+
+/// Compares two translation files by context and message, ignoring location and ordering changes by default.
+#[derive(Args)]
+#[command(disable_help_flag = true)]
+pub struct DiffArgs {
+    /// Previous version of the file, or `@group` for a group of a single file.
+    #[arg(help = tr!("cli-diff-old"), help_heading = tr!("cli-headers-arguments"))]
+    pub old_path: String,
+    /// Current version of the file, or `@group` for a group of a single file.
+    #[arg(help = tr!("cli-diff-new"), help_heading = tr!("cli-headers-arguments"))]
+    pub new_path: String,
+    /// How messages of both versions are identified as being the same message, within a context.
+    #[arg(short, long, value_enum, help = tr!("cli-diff-strategy"), help_heading = tr!("cli-headers-options"))]
+    pub strategy: Option<MatchStrategy>,
+    /// Also reports the messages whose locations changed.
+    #[arg(long, action = ArgAction::SetTrue, help = tr!("cli-diff-locations"), help_heading = tr!("cli-headers-options"))]
+    pub locations: bool,
+    /// Also reports the contexts and messages whose order changed.
+    #[arg(long, action = ArgAction::SetTrue, help = tr!("cli-diff-order"), help_heading = tr!("cli-headers-options"))]
+    pub order: bool,
+    /// Format of the report.
+    #[arg(short, long, value_enum, help = tr!("cli-diff-format"), help_heading = tr!("cli-headers-options"))]
+    pub format: Option<ReportFormat>,
+    /// If specified, will produce output in a file at designated location instead of stdout.
+    #[arg(short, long, help = tr!("cli-diff-output"), help_heading = tr!("cli-headers-options"))]
+    pub output_path: Option<String>,
+    #[arg(short, long, action = ArgAction::Help, help = tr!("cli-help"), help_heading = tr!("cli-headers-options"))]
+    pub help: Option<bool>,
+}
+
+/// Prints the differences between both files. Returns an error when there are differences, so that
+/// the command exits with a non-zero code.
+pub fn diff_main(args: &DiffArgs) -> Result<(), String> {
+    let config = current_config();
+    let old = ts::read_from_file(&config.expand_single_input(&args.old_path)?)?;
+    let new = ts::read_from_file(&config.expand_single_input(&args.new_path)?)?;
+
+    let report = DiffReport::new(&old, &new, &DiffOptions::new(args, config));
+    let output = report.format(args.format.or(config.diff.format).unwrap_or_default())?;
+
+    match &args.output_path {
+        None => print!("{output}"),
+        Some(output_path) => write_to_output(output_path, &output)?,
+    }
+
+    match report.count() {
+        0 => Ok(()),
+        count => Err(tr!("cli-diff-differences-found", count = count)),
+    }
+}
+
+/// Options of a comparison.
+struct DiffOptions {
+    strategy: MatchStrategy,
+    locations: bool,
+    order: bool,
+}
+
+impl DiffOptions {
+    /// Options of a comparison, from the command line arguments then the project configuration.
+    fn new(args: &DiffArgs, config: &Config) -> Self {
+        DiffOptions {
+            strategy: args
+                .strategy
+                .or(config.diff.strategy)
+                .unwrap_or(MatchStrategy::SourceCommentContext),
+            locations: args.locations || config.diff.locations,
+            order: args.order || config.diff.order,
+        }
+    }
+}
+
+/// Message of a context, in either or both versions of a file.
+pub(crate) struct MessagePair<'a> {
+    pub context: &'a str,
+    pub old: Option<&'a MessageNode>,
+    pub new: Option<&'a MessageNode>,
+}
+
+/// Pairs the messages of both versions of a file: contexts are matched by name, then messages by
+/// `strategy` within them. Messages come in the order of the new version, followed by the removed ones.
+pub(crate) fn pair_messages<'a>(
+    old: &'a TSNode,
+    new: &'a TSNode,
+    strategy: MatchStrategy,
+) -> Vec<MessagePair<'a>> {
+    let mut pairs = vec![];
+
+    for new_context in &new.contexts {
+        let old_messages = old
+            .contexts
+            .iter()
+            .find(|context| context.name == new_context.name)
+            .map(|context| context.messages.as_slice())
+            .unwrap_or_default();
+        let mut paired = vec![false; old_messages.len()];
+
+        for new_message in &new_context.messages {
+            let old_index = (0..old_messages.len()).find(|&index| {
+                !paired[index] && strategy.matches(&old_messages[index], new_message)
+            });
+            if let Some(index) = old_index {
+                paired[index] = true;
+            }
+
+            pairs.push(MessagePair {
+                context: &new_context.name,
+                old: old_index.map(|index| &old_messages[index]),
+                new: Some(new_message),
+            });
+        }
+
+        pairs.extend(
+            old_messages
+                .iter()
+                .zip(paired)
+                .filter(|(_, paired)| !paired)
+                .map(|(old_message, _)| MessagePair {
+                    context: &new_context.name,
+                    old: Some(old_message),
+                    new: None,
+                }),
+        );
+    }
+
+    for old_context in &old.contexts {
+        if !new
+            .contexts
+            .iter()
+            .any(|context| context.name == old_context.name)
+        {
+            pairs.extend(old_context.messages.iter().map(|old_message| MessagePair {
+                context: &old_context.name,
+                old: Some(old_message),
+                new: None,
+            }));
+        }
+    }
+
+    pairs
+}
+
+/// Differences between two versions of a translation file.
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct DiffReport {
+    contexts_added: Vec<String>,
+    contexts_removed: Vec<String>,
+    messages_added: Vec<MessageRef>,
+    messages_removed: Vec<MessageRef>,
+    messages_changed: Vec<MessageChanges>,
+    /// Contexts whose messages are in another order, with `--order`
+    messages_reordered: Vec<String>,
+    /// Whether the contexts are in another order, with `--order`
+    contexts_reordered: bool,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct MessageChanges {
+    context: String,
+    source: String,
+    changes: Vec<FieldChange>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct FieldChange {
+    field: &'static str,
+    old: Option<String>,
+    new: Option<String>,
+}
+
+impl DiffReport {
+    fn new(old: &TSNode, new: &TSNode, options: &DiffOptions) -> Self {
+        let context_names = |node: &TSNode| -> Vec<String> {
+            node.contexts
+                .iter()
+                .map(|context| context.name.clone())
+                .collect()
+        };
+        let (old_names, new_names) = (context_names(old), context_names(new));
+
+        let mut report = DiffReport {
+            contexts_added: new_names
+                .iter()
+                .filter(|name| !old_names.contains(name))
+                .cloned()
+                .collect(),
+            contexts_removed: old_names
+                .iter()
+                .filter(|name| !new_names.contains(name))
+                .cloned()
+                .collect(),
+            ..Default::default()
+        };
+
+        for pair in pair_messages(old, new, options.strategy) {
+            match (pair.old, pair.new) {
+                (None, Some(new_message)) => report
+                    .messages_added
+                    .push(MessageRef::new(pair.context, new_message)),
+                (Some(old_message), None) => report
+                    .messages_removed
+                    .push(MessageRef::new(pair.context, old_message)),
+                (Some(old_message), Some(new_message)) => {
+                    let changes = compare_messages(old_message, new_message, options.locations);
+                    if !changes.is_empty() {
+                        report.messages_changed.push(MessageChanges {
+                            context: pair.context.to_owned(),
+                            source: new_message.source.clone().unwrap_or_default(),
+                            changes,
+                        });
+                    }
+                }
+                (None, None) => {}
+            }
+        }
+
+        if options.order {
+            let common = |names: &[String], others: &[String]| -> Vec<String> {
+                names
+                    .iter()
+                    .filter(|name| others.contains(name))
+                    .cloned()
+                    .collect()
+            };
+            report.contexts_reordered =
+                common(&old_names, &new_names) != common(&new_names, &old_names);
+            report.messages_reordered = new
+                .contexts
+                .iter()
+                .filter(|context| {
+                    is_reordered(old, &context.name, &context.messages, options.strategy)
+                })
+                .map(|context| context.name.clone())
+                .collect();
+        }
+
+        report
+    }
+
+    /// Number of differences.
+    pub fn count(&self) -> usize {
+        self.contexts_added.len()
+            + self.contexts_removed.len()
+            + self.messages_added.len()
+            + self.messages_removed.len()
+            + self.messages_changed.len()
+            + self.messages_reordered.len()
+            + usize::from(self.contexts_reordered)
+    }
+
+    pub fn format(&self, format: ReportFormat) -> Result<String, String> {
+        match format {
+            ReportFormat::Text => Ok(self.to_text()),
+            ReportFormat::Json => to_json(self),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let message_line = |message: &MessageRef| {
+            tr!(
+                "cli-diff-message",
+                context = message.context.as_str(),
+                source = message.source.as_str()
+            )
+        };
+        let changes_line = |message: &MessageChanges| {
+            let mut line = tr!(
+                "cli-diff-message",
+                context = message.context.as_str(),
+                source = message.source.as_str()
+            );
+            for change in &message.changes {
+                let change = tr!(
+                    "cli-diff-change",
+                    field = change.field,
+                    old = change.old.as_deref().unwrap_or_default(),
+                    new = change.new.as_deref().unwrap_or_default()
+                );
+                line.push_str(&format!("\n    {change}"));
+            }
+            line
+        };
+
+        let contexts_added = self.contexts_added.len();
+        let contexts_removed = self.contexts_removed.len();
+        let messages_added = self.messages_added.len();
+        let messages_removed = self.messages_removed.len();
+        let messages_changed = self.messages_changed.len();
+        let messages_reordered = self.messages_reordered.len();
+        let sections = [
+            (
+                tr!("cli-diff-contexts-added", count = contexts_added),
+                self.contexts_added.clone(),
+            ),
+            (
+                tr!("cli-diff-contexts-removed", count = contexts_removed),
+                self.contexts_removed.clone(),
+            ),
+            (
+                tr!("cli-diff-messages-added", count = messages_added),
+                self.messages_added.iter().map(message_line).collect(),
+            ),
+            (
+                tr!("cli-diff-messages-removed", count = messages_removed),
+                self.messages_removed.iter().map(message_line).collect(),
+            ),
+            (
+                tr!("cli-diff-messages-changed", count = messages_changed),
+                self.messages_changed.iter().map(changes_line).collect(),
+            ),
+            (
+                tr!("cli-diff-messages-reordered", count = messages_reordered),
+                self.messages_reordered.clone(),
+            ),
+        ];
+
+        let mut text: String = sections
+            .into_iter()
+            .filter(|(_, lines)| !lines.is_empty())
+            .map(|(header, lines): (String, Vec<String>)| {
+                let lines: String = lines.iter().map(|line| format!("  {line}\n")).collect();
+                format!("{header}\n{lines}")
+            })
+            .collect();
+
+        if self.contexts_reordered {
+            text.push_str(&format!("{}\n", tr!("cli-diff-contexts-reordered")));
+        }
+        if text.is_empty() {
+            text = format!("{}\n", tr!("cli-diff-no-differences"));
+        }
+        text
+    }
+}
+
+fn compare_messages(old: &MessageNode, new: &MessageNode, locations: bool) -> Vec<FieldChange> {
+    let mut changes = vec![];
+    let mut compare = |field: &'static str, old: Option<String>, new: Option<String>| {
+        if old != new {
+            changes.push(FieldChange { field, old, new });
+        }
+    };
+
+    compare("source", old.source.clone(), new.source.clone());
+    compare("translation", translation_text(old), translation_text(new));
+    compare(
+        "status",
+        Some(old.status().to_string()),
+        Some(new.status().to_string()),
+    );
+    compare("comment", old.comment.clone(), new.comment.clone());
+    compare(
+        "extracomment",
+        old.extra_comment.clone(),
+        new.extra_comment.clone(),
+    );
+    compare(
+        "translatorcomment",
+        old.translator_comment.clone(),
+        new.translator_comment.clone(),
+    );
+
+    if locations {
+        let locations = |message: &MessageNode| {
+            let locations: Vec<String> = message
+                .locations
+                .iter()
+                .map(|location| {
+                    format!(
+                        "{}:{}",
+                        location.filename.as_deref().unwrap_or_default(),
+                        location.line.unwrap_or_default()
+                    )
+                })
+                .collect();
+            (!locations.is_empty()).then(|| locations.join(", "))
+        };
+        compare("locations", locations(old), locations(new));
+    }
+
+    changes
+}
+
+/// Whether the messages kept from the old version of a context are in another order.
+fn is_reordered(
+    old: &TSNode,
+    context: &str,
+    new_messages: &[MessageNode],
+    strategy: MatchStrategy,
+) -> bool {
+    let Some(old_context) = old.contexts.iter().find(|old| old.name == context) else {
+        return false;
+    };
+
+    let mut paired = vec![false; old_context.messages.len()];
+    let old_indexes: Vec<usize> = new_messages
+        .iter()
+        .filter_map(|new_message| {
+            let index = (0..paired.len()).find(|&index| {
+                !paired[index] && strategy.matches(&old_context.messages[index], new_message)
+            })?;
+            paired[index] = true;
+            Some(index)
+        })
+        .collect();
+
+    !old_indexes.is_sorted()
+}
+
+#[cfg(test)]
+mod diff_test {
+    use super::*;
+
+    fn diff_args(locations: bool, order: bool) -> DiffArgs {
+        DiffArgs {
+            old_path: "./test_data/example_diff_old.xml".to_owned(),
+            new_path: "./test_data/example_diff_new.xml".to_owned(),
+            strategy: None,
+            locations,
+            order,
+            format: None,
+            output_path: None,
+            help: None,
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let args = diff_args(false, false);
+        let old = ts::read_from_file(&args.old_path).expect("Test data could not be loaded.");
+        let new = ts::read_from_file(&args.new_path).expect("Test data could not be loaded.");
+
+        let report = DiffReport::new(&old, &new, &DiffOptions::new(&args, &Config::default()));
+
+        assert_eq!(report.count(), 7);
+        assert_eq!(
+            report.format(ReportFormat::Text).expect("Formattable"),
+            r#"Contexts added (1):
+  MainWindow
+Contexts removed (1):
+  Legacy
+Messages added (2):
+  Context "Dialog", source "Help"
+  Context "MainWindow", source "Quit"
+Messages removed (2):
+  Context "Dialog", source "Save"
+  Context "Legacy", source "Exit"
+Messages changed (1):
+  Context "Dialog", source "Close"
+    translation: "Schliesen" → "Schließen"
+    status: "unfinished" → "finished"
+"#
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&report.format(ReportFormat::Json).expect("Formattable"))
+                .expect("Valid JSON");
+        assert_eq!(json["messages_changed"][0]["changes"][1]["field"], "status");
+        assert_eq!(json["contexts_reordered"], false);
+
+        assert_eq!(
+            DiffReport::new(&old, &old, &DiffOptions::new(&args, &Config::default())).count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_diff_locations_and_order() {
+        let args = diff_args(true, true);
+        let old = ts::read_from_file(&args.old_path).expect("Test data could not be loaded.");
+        let new = ts::read_from_file(&args.new_path).expect("Test data could not be loaded.");
+
+        let report = DiffReport::new(&old, &new, &DiffOptions::new(&args, &Config::default()));
+
+        assert_eq!(report.messages_reordered, vec!["Dialog".to_owned()]);
+        assert!(!report.contexts_reordered);
+        assert_eq!(
+            report.messages_changed[1].changes,
+            vec![FieldChange {
+                field: "locations",
+                old: Some("dialog.cpp:10".to_owned()),
+                new: Some("dialog.cpp:12".to_owned()),
+            }]
+        );
+    }
+}
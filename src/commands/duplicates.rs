@@ -0,0 +1,311 @@
+use clap::{ArgAction, Args};
+use log::debug;
+
+use crate::commands::report::write_to_output;
+use crate::commands::text::translation_text;
+use crate::config::current_config;
+use crate::tr;
+use crate::ts;
+use crate::ts::{ContextNode, LocationNode, MessageNode, TSNode, TranslationType};
+
+/// Lists messages appearing more than once in a context, and optionally collapses them.
+#[derive(Args)]
+#[command(disable_help_flag = true)]
+pub struct DuplicatesArgs {
+    /// File path to look for duplicated messages in, or `@group` for a group of the configuration.
+    #[arg(help = tr!("cli-duplicates-input"), help_heading = tr!("cli-headers-arguments"))]
+    pub input_path: String,
+    /// When set, outputs the translation file with duplicates collapsed instead of the report.
+    #[arg(long, action = ArgAction::SetTrue, help = tr!("cli-duplicates-fix"), help_heading = tr!("cli-headers-options"))]
+    pub fix: bool,
+    /// If specified, will produce output in a file at designated location instead of stdout.
+    #[arg(short, long, help = tr!("cli-duplicates-output"), help_heading = tr!("cli-headers-options"))]
+    pub output_path: Option<String>,
+    #[arg(short, long, action = ArgAction::Help, help = tr!("cli-help"), help_heading = tr!("cli-headers-options"))]
+    pub help: Option<bool>,
+}
+
+/// Messages of a context sharing the same id, source and comment.
+#[derive(Debug, PartialEq)]
+struct DuplicateGroup {
+    context: String,
+    source: String,
+    /// Union of the locations of the duplicated messages.
+    locations: Vec<LocationNode>,
+    count: usize,
+    /// Distinct translations when the duplicates disagree, empty otherwise.
+    conflicting_translations: Vec<String>,
+}
+
+/// Reports the duplicated messages of the input file. With `--fix`, outputs the file with
+/// duplicates collapsed. In both cases, returns an error when a human has to look at the file:
+/// duplicates were found, or duplicates with conflicting translations were collapsed.
+pub fn duplicates_main(args: &DuplicatesArgs) -> Result<(), String> {
+    let config = current_config();
+
+    if args.fix {
+        let input_path = config.expand_single_input(&args.input_path)?;
+        let mut ts_node = ts::read_from_file(&input_path)?;
+        let groups = collapse_duplicates(&mut ts_node);
+
+        ts::write_to_output(&args.output_path, &ts_node)?;
+
+        let conflicts: Vec<&DuplicateGroup> = groups
+            .iter()
+            .filter(|group| !group.conflicting_translations.is_empty())
+            .collect();
+
+        if conflicts.is_empty() {
+            return Ok(());
+        }
+
+        eprint!("{}", generate_report(&conflicts));
+        return Err(tr!(
+            "cli-duplicates-conflicts-remaining",
+            count = conflicts.len(),
+            file = input_path.as_str()
+        ));
+    }
+
+    let input_paths = config.expand_input(&args.input_path)?;
+    let mut report = String::new();
+    let mut errors = vec![];
+
+    for input_path in &input_paths {
+        let mut ts_node = ts::read_from_file(input_path)?;
+        let groups = collapse_duplicates(&mut ts_node);
+
+        if groups.is_empty() {
+            continue;
+        }
+
+        // Files of a group are told apart by their path
+        if input_paths.len() > 1 {
+            report.push_str(&format!("{input_path}\n"));
+        }
+        report.push_str(&generate_report(&groups.iter().collect::<Vec<_>>()));
+        errors.push(tr!(
+            "cli-duplicates-found",
+            count = groups.len(),
+            file = input_path.as_str()
+        ));
+    }
+
+    match &args.output_path {
+        None => print!("{report}"),
+        Some(output_path) => write_to_output(output_path, &report)?,
+    }
+
+    match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors.join("\n")),
+    }
+}
+
+/// Collapses messages sharing the same id, source and comment within each context, keeping
+/// the position of the first one. The finished translation is kept and locations are merged.
+fn collapse_duplicates(ts_node: &mut TSNode) -> Vec<DuplicateGroup> {
+    let mut groups = vec![];
+
+    for context in &mut ts_node.contexts {
+        groups.extend(collapse_context(context));
+    }
+
+    debug!("Found {} duplicated messages", groups.len());
+
+    groups
+}
+
+fn collapse_context(context: &mut ContextNode) -> Vec<DuplicateGroup> {
+    let mut groups = vec![];
+    let mut collapsed: Vec<Vec<MessageNode>> = vec![];
+
+    for message in context.messages.drain(..) {
+        match collapsed
+            .iter_mut()
+            .find(|duplicates| is_duplicate(&duplicates[0], &message))
+        {
+            Some(duplicates) => duplicates.push(message),
+            None => collapsed.push(vec![message]),
+        }
+    }
+
+    for duplicates in collapsed {
+        if duplicates.len() == 1 {
+            context.messages.extend(duplicates);
+            continue;
+        }
+
+        let (message, conflicting_translations) = merge_duplicates(&duplicates);
+
+        groups.push(DuplicateGroup {
+            context: context.name.clone(),
+            source: message.source.clone().unwrap_or_default(),
+            locations: message.locations.clone(),
+            count: duplicates.len(),
+            conflicting_translations,
+        });
+        context.messages.push(message);
+    }
+
+    groups
+}
+
+fn is_duplicate(left: &MessageNode, right: &MessageNode) -> bool {
+    left.id == right.id && left.source == right.source && left.comment == right.comment
+}
+
+fn is_finished(message: &MessageNode) -> bool {
+    message.translation.as_ref().is_some_and(|translation| {
+        matches!(
+            translation.translation_type,
+            None | Some(TranslationType::Finished)
+        )
+    }) && translation_text(message).is_some()
+}
+
+/// Merges duplicated messages into the one holding the translation to keep: the first
+/// finished one, else the first translated one still in use, else the first one in use.
+/// Also returns the distinct translations if more than one candidate translation exists.
+fn merge_duplicates(duplicates: &[MessageNode]) -> (MessageNode, Vec<String>) {
+    let candidates: Vec<&MessageNode> = match duplicates.iter().any(is_finished) {
+        true => duplicates
+            .iter()
+            .filter(|message| is_finished(message))
+            .collect(),
+        false => duplicates
+            .iter()
+            .filter(|message| message.status().is_in_use() && translation_text(message).is_some())
+            .collect(),
+    };
+
+    let mut merged = candidates
+        .first()
+        .copied()
+        .or_else(|| {
+            duplicates
+                .iter()
+                .find(|message| message.status().is_in_use())
+        })
+        .unwrap_or(&duplicates[0])
+        .clone();
+
+    for location in duplicates.iter().flat_map(|message| &message.locations) {
+        if !merged.locations.contains(location) {
+            merged.locations.push(location.clone());
+        }
+    }
+
+    let mut translations: Vec<String> = vec![];
+    for text in candidates
+        .iter()
+        .filter_map(|message| translation_text(message))
+    {
+        if !translations.contains(&text) {
+            translations.push(text);
+        }
+    }
+
+    if translations.len() < 2 {
+        translations.clear();
+    }
+
+    (merged, translations)
+}
+
+/// Generates the report of duplicated messages, grouped by context.
+fn generate_report(groups: &[&DuplicateGroup]) -> String {
+    let mut report = String::new();
+    let mut current_context = None;
+
+    for group in groups {
+        if current_context != Some(&group.context) {
+            report.push_str(&format!("{}\n", group.context));
+            current_context = Some(&group.context);
+        }
+
+        let locations = group
+            .locations
+            .iter()
+            .map(|location| {
+                let filename = location.filename.as_deref().unwrap_or_default();
+                match location.line {
+                    Some(line) => format!("{filename}:{line}"),
+                    None => filename.to_owned(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        report.push_str(&format!(
+            "    {}\n",
+            tr!(
+                "cli-duplicates-message",
+                source = group.source.as_str(),
+                count = group.count,
+                locations = locations
+            )
+        ));
+
+        if !group.conflicting_translations.is_empty() {
+            let translations = group
+                .conflicting_translations
+                .iter()
+                .map(|translation| format!("\"{translation}\""))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            report.push_str(&format!(
+                "        {}\n",
+                tr!("cli-duplicates-conflict", translations = translations)
+            ));
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod duplicates_test {
+    use crate::commands::test_utils::{load_test_file, node_to_formatted_string, read_test_file};
+    use crate::ts::MessageStatus;
+
+    use super::*;
+
+    #[test]
+    fn test_duplicates_report() {
+        let mut data = load_test_file("example_duplicates.xml");
+
+        let groups = collapse_duplicates(&mut data);
+        let report = generate_report(&groups.iter().collect::<Vec<_>>());
+
+        assert_eq!(
+            report,
+            "Dialog\n    \"Save\" appears 3 times (dialog.cpp:12, dialog.cpp:40, main.cpp:8).\n        Conflicting translations: \"Speichern\", \"Sichern\"\n    \"Open\" appears 2 times (dialog.cpp:20, dialog.cpp:50).\nToolbar\n    \"%n file(s)\" appears 2 times (toolbar.cpp:9, toolbar.cpp:5).\n"
+        );
+    }
+
+    #[test]
+    fn test_collapse_duplicates() {
+        let expected = read_test_file("example_duplicates_collapsed.xml");
+        let mut data = load_test_file("example_duplicates.xml");
+
+        collapse_duplicates(&mut data);
+
+        assert_eq!(expected, node_to_formatted_string(&data));
+    }
+
+    #[test]
+    fn test_collapse_duplicates_keeps_message_in_use() {
+        let mut data = load_test_file("example_duplicates_obsolete.xml");
+
+        let groups = collapse_duplicates(&mut data);
+
+        assert!(groups[0].conflicting_translations.is_empty());
+        let messages = &data.contexts[0].messages;
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].status(), MessageStatus::Unfinished);
+        assert_eq!(messages[0].locations[0].line, Some(16));
+        assert_eq!(messages[0].locations.len(), 2);
+    }
+}
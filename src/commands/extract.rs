@@ -1,7 +1,7 @@
 use clap::{ArgAction, Args};
-use i18n_embed_fl::fl;
 use log::debug;
 
+use crate::config::current_config;
 use crate::ts::{TSNode, TranslationNode, TranslationType};
 use crate::{tr, ts};
 
@@ -16,7 +16,7 @@ pub enum TranslationTypeArg {
 #[derive(Args)]
 #[command(disable_help_flag = true)]
 pub struct ExtractArgs {
-    /// File path to extract translations from.
+    /// File path to extract translations from, or `@group` for a group of a single file.
     #[arg(help = tr!("cli-extract-input"), help_heading = tr!("cli-headers-arguments"))]
     pub input_path: String,
     /// Translation type list to extract into a single, valid translation output.
@@ -31,33 +31,16 @@ pub struct ExtractArgs {
 
 /// Filters the translation file to keep only the messages containing unfinished translations.
 pub fn extract_main(extract_args: &ExtractArgs) -> Result<(), String> {
-    match quick_xml::Reader::from_file(&extract_args.input_path) {
-        Ok(file) => {
-            let nodes: Result<TSNode, _> = quick_xml::de::from_reader(file.into_inner());
-            match nodes {
-                Ok(mut ts_node) => {
-                    let wanted_types = extract_args
-                        .translation_type
-                        .iter()
-                        .map(to_translation_type)
-                        .collect::<Vec<TranslationType>>();
-                    retain_ts_node(&mut ts_node, &wanted_types);
-                    ts::write_to_output(&extract_args.output_path, &ts_node)
-                }
-                Err(e) => Err(fl!(
-                    crate::locale::current_loader(),
-                    "error-open-or-parse",
-                    file = extract_args.input_path.as_str(),
-                    error = e.to_string()
-                )),
-            }
-        }
-        Err(e) => Err(tr!(
-            "error-open-or-parse",
-            file = extract_args.input_path.as_str(),
-            error = e.to_string()
-        )),
-    }
+    let input_path = current_config().expand_single_input(&extract_args.input_path)?;
+    let mut ts_node = ts::read_from_file(&input_path)?;
+
+    let wanted_types = extract_args
+        .translation_type
+        .iter()
+        .map(to_translation_type)
+        .collect::<Vec<TranslationType>>();
+    retain_ts_node(&mut ts_node, &wanted_types);
+    ts::write_to_output(&extract_args.output_path, &ts_node)
 }
 
 fn to_translation_type(value: &TranslationTypeArg) -> TranslationType {
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use crate::tr;
+use crate::ts::ContextNode;
+
+use super::{Diagnostic, linted_messages, linted_texts};
+
+pub const RULE: &str = "accelerators";
+
+/// Lists the mnemonic characters of `text` (the character following a single `&`), lowercased.
+/// A doubled `&&` is a literal ampersand, an `&` followed by a whitespace is not a mnemonic
+/// (e.g. "Drag & Drop") and neither is a rich text entity (e.g. `&nbsp;`).
+pub fn parse_mnemonics(text: &str) -> Vec<char> {
+    let mut mnemonics = vec![];
+    let mut rest = text;
+
+    while let Some(position) = rest.find('&') {
+        rest = &rest[position + 1..];
+
+        let mut chars = rest.chars();
+        match chars.next() {
+            Some('&') => rest = chars.as_str(),
+            Some(_) if is_entity(rest) => {}
+            Some(next) if !next.is_whitespace() => mnemonics.extend(next.to_lowercase()),
+            _ => {}
+        }
+    }
+
+    mnemonics
+}
+
+/// Whether `text`, which follows an `&`, is the remainder of an entity such as `&nbsp;`.
+fn is_entity(text: &str) -> bool {
+    text.find(|c: char| !(c.is_ascii_alphanumeric() || c == '#'))
+        .is_some_and(|end| end > 0 && text[end..].starts_with(';'))
+}
+
+/// Verifies that keyboard accelerators are kept by translators:
+/// * The translation must have as many `&` mnemonic markers as the source;
+/// * Two messages of a same context must not use the same mnemonic in their translation,
+///   unless their source already shared that mnemonic.
+pub fn check_accelerators(context_index: usize, context: &ContextNode) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    let mut used_mnemonics = HashMap::<char, (&str, Option<char>)>::new();
+
+    for linted_message in linted_messages(context_index, context) {
+        let message = linted_message.message;
+        let Some(source) = message.source.as_ref() else {
+            continue;
+        };
+
+        let source_mnemonics = parse_mnemonics(source);
+
+        for text in linted_texts(message) {
+            let translation_mnemonics = parse_mnemonics(text.text);
+
+            if translation_mnemonics.len() < source_mnemonics.len() {
+                diagnostics.extend(Diagnostic::for_message(
+                    RULE,
+                    linted_message,
+                    tr!("cli-lint-accelerator-missing", target = text.target()),
+                ));
+            } else if translation_mnemonics.len() > source_mnemonics.len() {
+                diagnostics.extend(Diagnostic::for_message(
+                    RULE,
+                    linted_message,
+                    tr!("cli-lint-accelerator-extra", target = text.target()),
+                ));
+            }
+
+            // Numerus forms are alternatives of the same message, only the first form is
+            // considered for conflicts between messages.
+            if text.numerus_index.unwrap_or(0) != 0 {
+                continue;
+            }
+
+            let Some(mnemonic) = translation_mnemonics.first() else {
+                continue;
+            };
+
+            match used_mnemonics.get(mnemonic) {
+                Some((other_translation, other_source_mnemonic))
+                    if *other_source_mnemonic != source_mnemonics.first().copied()
+                        || other_source_mnemonic.is_none() =>
+                {
+                    diagnostics.extend(Diagnostic::for_message(
+                        RULE,
+                        linted_message,
+                        tr!(
+                            "cli-lint-accelerator-duplicated",
+                            mnemonic = mnemonic.to_string(),
+                            other = other_translation.to_string()
+                        ),
+                    ));
+                }
+                Some(_) => {}
+                None => {
+                    used_mnemonics
+                        .insert(*mnemonic, (text.text, source_mnemonics.first().copied()));
+                }
+            }
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod accelerators_test {
+    use super::*;
+
+    #[test]
+    fn test_parse_mnemonics() {
+        assert_eq!(parse_mnemonics("&File"), vec!['f']);
+        assert_eq!(parse_mnemonics("Save &As..."), vec!['a']);
+        assert_eq!(parse_mnemonics("Drag & Drop && &Copy"), vec!['c']);
+        assert_eq!(parse_mnemonics("Trailing &"), vec![]);
+        assert_eq!(parse_mnemonics("&nbsp;&Next"), vec!['n']);
+    }
+}
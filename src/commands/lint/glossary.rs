@@ -0,0 +1,240 @@
+use serde::Deserialize;
+
+use crate::options::{TermMatch, language_matches};
+use crate::tr;
+use crate::ts::ContextNode;
+
+use super::{Diagnostic, linted_messages, linted_texts};
+
+pub const RULE: &str = "glossary";
+
+/// Qt Linguist phrase book (`.qph`).
+#[derive(Debug, Deserialize)]
+#[serde(rename = "QPH")]
+struct PhraseBook {
+    #[serde(rename = "@language")]
+    language: Option<String>,
+    #[serde(rename = "phrase", default)]
+    phrases: Vec<Phrase>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Phrase {
+    source: String,
+    target: String,
+}
+
+/// Approved translation of a term.
+#[derive(Debug, PartialEq)]
+struct Term {
+    source: String,
+    target: String,
+}
+
+/// Terms to enforce for a given language.
+#[derive(Debug, Default)]
+pub struct Glossary {
+    terms: Vec<Term>,
+    term_match: TermMatch,
+}
+
+/// Header line of a TSV glossary restricting it to a language, e.g. `# language: de`.
+const TSV_LANGUAGE_HEADER: &str = "# language:";
+
+impl Glossary {
+    pub fn new(term_match: TermMatch) -> Glossary {
+        Glossary {
+            terms: vec![],
+            term_match,
+        }
+    }
+
+    /// Loads the terms of a glossary file if it applies to `language`.
+    /// A phrase book (`.qph`) applies to its `language` attribute. A tab separated file
+    /// (`source<TAB>target` per line) applies to the language of its `# language: xx` header.
+    /// Without language, the glossary applies to every language.
+    pub fn load(&mut self, path: &str, language: &str) -> Result<(), String> {
+        let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+
+        let (glossary_language, terms) = if path.to_lowercase().ends_with(".qph") {
+            parse_phrase_book(&content)?
+        } else {
+            parse_tsv(&content)
+        };
+
+        match glossary_language {
+            Some(glossary_language) if !language_matches(language, &glossary_language) => {}
+            _ => self.terms.extend(terms),
+        }
+
+        Ok(())
+    }
+
+    fn contains(&self, words: &[String], term: &str) -> bool {
+        let term_words = split_words(term);
+
+        !term_words.is_empty()
+            && words.windows(term_words.len()).any(|window| {
+                window
+                    .iter()
+                    .zip(&term_words)
+                    .all(|(word, term_word)| match self.term_match {
+                        TermMatch::Word => word == term_word,
+                        TermMatch::Prefix => word.starts_with(term_word.as_str()),
+                    })
+            })
+    }
+}
+
+fn parse_phrase_book(content: &str) -> Result<(Option<String>, Vec<Term>), String> {
+    let phrase_book: PhraseBook = quick_xml::de::from_str(content).map_err(|e| e.to_string())?;
+
+    Ok((
+        phrase_book.language,
+        phrase_book
+            .phrases
+            .into_iter()
+            .map(|phrase| Term {
+                source: phrase.source,
+                target: phrase.target,
+            })
+            .collect(),
+    ))
+}
+
+fn parse_tsv(content: &str) -> (Option<String>, Vec<Term>) {
+    let mut language = None;
+    let mut terms = vec![];
+
+    for line in content.lines() {
+        if let Some(header) = line.strip_prefix(TSV_LANGUAGE_HEADER) {
+            language = Some(header.trim().to_owned());
+        } else if !line.starts_with('#')
+            && let Some((source, target)) = line.split_once('\t')
+        {
+            terms.push(Term {
+                source: source.trim().to_owned(),
+                target: target.trim().to_owned(),
+            });
+        }
+    }
+
+    (language, terms)
+}
+
+/// Lowercase words of a text. Accelerator markers are removed first so that "&Workspace" or
+/// "Work&space" still count as "workspace".
+fn split_words(text: &str) -> Vec<String> {
+    text.replace('&', "")
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Verifies that when a source contains a glossary term, its translation uses the approved target term.
+/// When a term has several approved targets, any of them is accepted.
+pub fn check_glossary(
+    context_index: usize,
+    context: &ContextNode,
+    glossary: &Glossary,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    if glossary.terms.is_empty() {
+        return diagnostics;
+    }
+
+    for linted_message in linted_messages(context_index, context) {
+        let message = linted_message.message;
+        let Some(source) = message.source.as_ref() else {
+            continue;
+        };
+
+        let source_words = split_words(source);
+        let mut terms: Vec<&Term> = glossary
+            .terms
+            .iter()
+            .filter(|term| glossary.contains(&source_words, &term.source))
+            .collect();
+        // A term with several approved targets is reported once
+        terms.sort_by(|left, right| left.source.cmp(&right.source));
+        terms.dedup_by(|left, right| left.source == right.source);
+
+        for text in linted_texts(message) {
+            let words = split_words(text.text);
+
+            for term in &terms {
+                let targets: Vec<&str> = glossary
+                    .terms
+                    .iter()
+                    .filter(|other| other.source == term.source)
+                    .map(|other| other.target.as_str())
+                    .collect();
+
+                if !targets
+                    .iter()
+                    .any(|target| glossary.contains(&words, target))
+                {
+                    diagnostics.extend(Diagnostic::for_message(
+                        RULE,
+                        linted_message,
+                        tr!(
+                            "cli-lint-glossary-term",
+                            term = term.source.as_str(),
+                            expected = targets.join("\", \""),
+                            target = text.target()
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod glossary_test {
+    use super::*;
+
+    #[test]
+    fn test_load_glossaries() {
+        let mut glossary = Glossary::default();
+        glossary
+            .load("./test_data/example_glossary.qph", "de_DE")
+            .expect("Phrase book is loadable");
+        glossary
+            .load("./test_data/example_glossary.tsv", "de_DE")
+            .expect("TSV glossary is loadable");
+
+        assert_eq!(
+            glossary.terms,
+            vec![
+                Term {
+                    source: "Workspace".to_owned(),
+                    target: "Arbeitsbereich".to_owned()
+                },
+                Term {
+                    source: "Save as".to_owned(),
+                    target: "Speichern unter".to_owned()
+                }
+            ]
+        );
+
+        let mut glossary = Glossary::default();
+        glossary
+            .load("./test_data/example_glossary.qph", "fr")
+            .expect("Phrase book is loadable");
+        assert!(glossary.terms.is_empty());
+    }
+
+    #[test]
+    fn test_contains_with_term_match() {
+        let words = split_words("Open the Workspaces");
+
+        assert!(!Glossary::new(TermMatch::Word).contains(&words, "workspace"));
+        assert!(Glossary::new(TermMatch::Prefix).contains(&words, "Workspace"));
+        assert!(Glossary::new(TermMatch::Word).contains(&words, "the workspaces"));
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use crate::options::LengthUnit;
+use crate::tr;
+use crate::ts::{ContextNode, MessageNode};
+
+use super::{Diagnostic, LintOptions, linted_messages, linted_texts};
+
+pub const RULE: &str = "max-length";
+
+/// Length limits declared outside the translation file, by context then by message id or source.
+/// A `*` key applies to every message of the context.
+#[derive(Default)]
+pub struct MaxLengths {
+    limits: HashMap<String, HashMap<String, usize>>,
+}
+
+/// Annotation that developers can put in the extra comment of a message, e.g. `maxlen:20`.
+const MAX_LENGTH_ANNOTATION: &str = "maxlen:";
+
+impl MaxLengths {
+    /// Parses a tab separated file where each line is `context<TAB>id or source<TAB>limit`.
+    /// Empty lines and lines starting with `#` are ignored.
+    pub fn parse(content: &str) -> Result<MaxLengths, String> {
+        let mut max_lengths = MaxLengths::default();
+
+        for (index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut columns = line.split('\t');
+            let parsed = match (columns.next(), columns.next(), columns.next()) {
+                (Some(context), Some(key), Some(limit)) => limit
+                    .trim()
+                    .parse::<usize>()
+                    .ok()
+                    .map(|limit| (context, key, limit)),
+                _ => None,
+            };
+
+            let Some((context, key, limit)) = parsed else {
+                let line_number = index + 1;
+                return Err(tr!(
+                    "cli-lint-max-length-invalid-line",
+                    line = line_number,
+                    content = line
+                ));
+            };
+
+            max_lengths
+                .limits
+                .entry(context.to_owned())
+                .or_default()
+                .insert(key.to_owned(), limit);
+        }
+
+        Ok(max_lengths)
+    }
+
+    /// Limit of a message, looked up in this order: the `extra-maxlength` element,
+    /// the `maxlen:` annotation of the extra comment, then the side configuration.
+    fn limit(&self, context: &ContextNode, message: &MessageNode) -> Option<usize> {
+        let from_element = message
+            .max_length
+            .as_ref()
+            .and_then(|value| value.trim().parse().ok());
+
+        let from_comment = || {
+            let comment = message.extra_comment.as_ref()?;
+            let start = comment.find(MAX_LENGTH_ANNOTATION)? + MAX_LENGTH_ANNOTATION.len();
+            let digits: String = comment[start..]
+                .trim_start()
+                .chars()
+                .take_while(char::is_ascii_digit)
+                .collect();
+            digits.parse().ok()
+        };
+
+        let from_config = || {
+            let limits = self.limits.get(&context.name)?;
+            message
+                .id
+                .as_ref()
+                .and_then(|id| limits.get(id))
+                .or_else(|| {
+                    message
+                        .source
+                        .as_ref()
+                        .and_then(|source| limits.get(source))
+                })
+                .or_else(|| limits.get("*"))
+                .copied()
+        };
+
+        from_element.or_else(from_comment).or_else(from_config)
+    }
+}
+
+/// Verifies that translations, including each numerus form, fit in their maximum length.
+pub fn check_max_length(
+    context_index: usize,
+    context: &ContextNode,
+    options: &LintOptions,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    for linted_message in linted_messages(context_index, context) {
+        let message = linted_message.message;
+        let Some(limit) = options.max_lengths.limit(context, message) else {
+            continue;
+        };
+
+        for text in linted_texts(message) {
+            let length = options.length_unit.measure(text.text);
+
+            if length > limit {
+                diagnostics.extend(Diagnostic::for_message(
+                    RULE,
+                    linted_message,
+                    tr!(
+                        "cli-lint-max-length-exceeded",
+                        target = text.target(),
+                        length = length,
+                        limit = limit,
+                        unit = match options.length_unit {
+                            LengthUnit::Chars => tr!("cli-lint-length-unit-chars"),
+                            LengthUnit::Utf16 => tr!("cli-lint-length-unit-utf16"),
+                        }
+                    ),
+                ));
+            }
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod length_test {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        assert_eq!(LengthUnit::Chars.measure("Öffnen 😀"), 8);
+        assert_eq!(LengthUnit::Utf16.measure("Öffnen 😀"), 9);
+    }
+
+    #[test]
+    fn test_parse_max_lengths() {
+        assert!(MaxLengths::parse("# comment\n\nDialog\tOK\t5\n").is_ok());
+        assert!(MaxLengths::parse("Dialog\tOK\tfive\n").is_err());
+        assert!(MaxLengths::parse("Dialog OK 5\n").is_err());
+    }
+}
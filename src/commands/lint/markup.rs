@@ -0,0 +1,367 @@
+use std::collections::BTreeMap;
+
+use crate::tr;
+use crate::ts::ContextNode;
+
+use super::{Diagnostic, linted_messages, linted_texts};
+
+pub const RULE: &str = "markup";
+
+/// HTML elements that never have a closing tag.
+const VOID_ELEMENTS: [&str; 6] = ["br", "hr", "img", "input", "meta", "link"];
+
+#[derive(Debug, PartialEq)]
+enum TagKind {
+    Open,
+    Close,
+    SelfClosing,
+}
+
+#[derive(Debug, PartialEq)]
+struct Tag {
+    /// Lowercase name of the element
+    name: String,
+    kind: TagKind,
+    /// Target of the `href` attribute, if any
+    href: Option<String>,
+}
+
+/// Rich text elements found in a text: tags and entities (`&nbsp;`, `&#169;`...).
+#[derive(Debug, Default, PartialEq)]
+struct Markup {
+    tags: Vec<Tag>,
+    entities: Vec<String>,
+}
+
+impl Markup {
+    /// Number of times each element is opened (or self-closed).
+    fn element_counts(&self) -> BTreeMap<&str, usize> {
+        let mut counts = BTreeMap::new();
+        self.tags
+            .iter()
+            .filter(|tag| tag.kind != TagKind::Close)
+            .for_each(|tag| *counts.entry(tag.name.as_str()).or_insert(0) += 1);
+        counts
+    }
+
+    fn href_counts(&self) -> BTreeMap<&str, usize> {
+        let mut counts = BTreeMap::new();
+        self.tags
+            .iter()
+            .filter_map(|tag| tag.href.as_deref())
+            .for_each(|href| *counts.entry(href).or_insert(0) += 1);
+        counts
+    }
+
+    fn entity_counts(&self) -> BTreeMap<&str, usize> {
+        let mut counts = BTreeMap::new();
+        self.entities
+            .iter()
+            .for_each(|entity| *counts.entry(entity.as_str()).or_insert(0) += 1);
+        counts
+    }
+
+    /// Names of the elements that are closed without being opened, or never closed.
+    fn unbalanced_elements(&self) -> Vec<&str> {
+        let mut unbalanced = vec![];
+        let mut stack: Vec<&str> = vec![];
+
+        for tag in &self.tags {
+            match tag.kind {
+                TagKind::SelfClosing => {}
+                TagKind::Open if VOID_ELEMENTS.contains(&tag.name.as_str()) => {}
+                TagKind::Open => stack.push(&tag.name),
+                TagKind::Close => match stack.iter().rposition(|name| *name == tag.name) {
+                    Some(position) => {
+                        unbalanced.extend(stack.drain(position..).skip(1));
+                    }
+                    None => unbalanced.push(&tag.name),
+                },
+            }
+        }
+
+        unbalanced.extend(stack);
+        unbalanced.sort();
+        unbalanced.dedup();
+        unbalanced
+    }
+}
+
+/// Splits the attributes of a tag into `name=value` pairs. Values may be quoted, and attributes
+/// without value have none.
+fn parse_attributes(attributes: &str) -> Vec<(&str, Option<&str>)> {
+    let mut parsed = vec![];
+    let mut rest = attributes.trim_start();
+
+    while let Some(first) = rest.chars().next() {
+        let name_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=')
+            .unwrap_or(rest.len());
+        if name_end == 0 {
+            // A stray `=`, there is no name to give it to
+            rest = rest[first.len_utf8()..].trim_start();
+            continue;
+        }
+        let name = &rest[..name_end];
+        rest = rest[name_end..].trim_start();
+
+        let value = match rest.strip_prefix('=') {
+            None => None,
+            Some(after) => {
+                let after = after.trim_start();
+                let (value, remaining) = match after.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let quoted = &after[1..];
+                        match quoted.find(quote) {
+                            Some(end) => (&quoted[..end], &quoted[end + 1..]),
+                            None => (quoted, ""),
+                        }
+                    }
+                    _ => {
+                        let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                        (&after[..end], &after[end..])
+                    }
+                };
+                rest = remaining.trim_start();
+                Some(value)
+            }
+        };
+
+        parsed.push((name, value));
+    }
+
+    parsed
+}
+
+fn parse_href(attributes: &str) -> Option<String> {
+    parse_attributes(attributes)
+        .into_iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case("href"))
+        .and_then(|(_, value)| value)
+        .map(str::to_owned)
+}
+
+fn parse_tag(inner: &str) -> Option<Tag> {
+    let (kind, inner) = match (inner.strip_prefix('/'), inner.strip_suffix('/')) {
+        (Some(inner), _) => (TagKind::Close, inner),
+        (None, Some(inner)) => (TagKind::SelfClosing, inner),
+        (None, None) => (TagKind::Open, inner),
+    };
+
+    let name_end = inner
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(inner.len());
+    let name = &inner[..name_end];
+
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    Some(Tag {
+        name: name.to_ascii_lowercase(),
+        href: match kind {
+            TagKind::Close => None,
+            _ => parse_href(&inner[name_end..]),
+        },
+        kind,
+    })
+}
+
+/// Position of the `>` ending a tag, skipping the quoted attribute values which may contain one.
+fn tag_end(inner: &str) -> Option<usize> {
+    let mut quote = None;
+    let mut after_equals = false;
+
+    for (index, c) in inner.char_indices() {
+        match quote {
+            Some(opening) if c == opening => quote = None,
+            Some(_) => continue,
+            None if c == '>' => return Some(index),
+            None if after_equals && (c == '"' || c == '\'') => quote = Some(c),
+            None => {}
+        }
+
+        if quote.is_none() && !c.is_whitespace() {
+            after_equals = c == '=';
+        }
+    }
+
+    None
+}
+
+/// Extracts the tags and entities of a Qt rich text.
+/// Anything that does not look like a tag (e.g. `a < b`) is considered plain text.
+fn parse_markup(text: &str) -> Markup {
+    let mut markup = Markup::default();
+    let mut rest = text;
+
+    while let Some(position) = rest.find(['<', '&']) {
+        let after = &rest[position..];
+
+        let consumed = if let Some(inner) = after.strip_prefix('<') {
+            match tag_end(inner).and_then(|end| Some((end, parse_tag(&inner[..end])?))) {
+                Some((end, tag)) => {
+                    markup.tags.push(tag);
+                    end + 2
+                }
+                None => 1,
+            }
+        } else {
+            let entity_end = after[1..]
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '#'))
+                .map(|end| end + 1);
+
+            match entity_end {
+                Some(end) if end > 1 && after[end..].starts_with(';') => {
+                    markup.entities.push(after[..=end].to_owned());
+                    end + 1
+                }
+                _ => 1,
+            }
+        };
+
+        rest = &after[consumed..];
+    }
+
+    markup
+}
+
+/// Verifies that rich text translations keep the markup of their source:
+/// * Every element of the source exists in the translation and no element is added;
+/// * Elements of the translation are properly closed (when they are in the source);
+/// * Link targets (`href`) are not changed;
+/// * Entities (`&nbsp;`...) are neither removed nor added.
+pub fn check_markup(context_index: usize, context: &ContextNode) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    for linted_message in linted_messages(context_index, context) {
+        let message = linted_message.message;
+        let Some(source) = message.source.as_ref() else {
+            continue;
+        };
+
+        let source_markup = parse_markup(source);
+        let source_elements = source_markup.element_counts();
+        let source_hrefs = source_markup.href_counts();
+        let source_entities = source_markup.entity_counts();
+        let source_is_balanced = source_markup.unbalanced_elements().is_empty();
+
+        for text in linted_texts(message) {
+            let markup = parse_markup(text.text);
+            let target = text.target();
+            let mut report = |description: String| {
+                diagnostics.extend(Diagnostic::for_message(RULE, linted_message, description))
+            };
+
+            let elements = markup.element_counts();
+            for name in diff_counts(&source_elements, &elements) {
+                report(tr!(
+                    "cli-lint-markup-tag-missing",
+                    tag = name,
+                    target = target.clone()
+                ));
+            }
+            for name in diff_counts(&elements, &source_elements) {
+                report(tr!(
+                    "cli-lint-markup-tag-added",
+                    tag = name,
+                    target = target.clone()
+                ));
+            }
+
+            if source_is_balanced {
+                for name in markup.unbalanced_elements() {
+                    report(tr!(
+                        "cli-lint-markup-tag-unbalanced",
+                        tag = name,
+                        target = target.clone()
+                    ));
+                }
+            }
+
+            for href in diff_counts(&source_hrefs, &markup.href_counts()) {
+                report(tr!(
+                    "cli-lint-markup-href-changed",
+                    href = href,
+                    target = target.clone()
+                ));
+            }
+
+            let entities = markup.entity_counts();
+            for entity in diff_counts(&source_entities, &entities) {
+                report(tr!(
+                    "cli-lint-markup-entity-missing",
+                    entity = entity,
+                    target = target.clone()
+                ));
+            }
+            for entity in diff_counts(&entities, &source_entities) {
+                report(tr!(
+                    "cli-lint-markup-entity-added",
+                    entity = entity,
+                    target = target.clone()
+                ));
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// Lists the keys of `left` that appear more often than in `right`.
+fn diff_counts<'a>(left: &BTreeMap<&'a str, usize>, right: &BTreeMap<&str, usize>) -> Vec<&'a str> {
+    left.iter()
+        .filter(|(key, count)| **count > right.get(*key).copied().unwrap_or(0))
+        .map(|(key, _)| *key)
+        .collect()
+}
+
+#[cfg(test)]
+mod markup_test {
+    use super::*;
+
+    #[test]
+    fn test_parse_markup() {
+        let markup = parse_markup(
+            "<b>Bold</b> a < b <a href=\"https://qt.io\">link</a><br/>&nbsp;&#169; && Drag & Drop",
+        );
+
+        assert_eq!(
+            markup
+                .tags
+                .iter()
+                .map(|tag| (tag.name.as_str(), tag.href.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("b", None),
+                ("b", None),
+                ("a", Some("https://qt.io")),
+                ("a", None),
+                ("br", None)
+            ]
+        );
+        assert_eq!(markup.entities, vec!["&nbsp;", "&#169;"]);
+        assert!(markup.unbalanced_elements().is_empty());
+        assert_eq!(
+            parse_markup("<p><b>Unclosed</p></i>").unbalanced_elements(),
+            vec!["b", "i"]
+        );
+    }
+
+    #[test]
+    fn test_parse_href() {
+        let href = |text: &str| parse_markup(text).tags[0].href.clone();
+
+        // Lowercasing `İ` changes its length in bytes
+        assert_eq!(href("<a title=\"İİİ\" href=\"x\">"), Some("x".to_owned()));
+        assert_eq!(href("<a title='İİİ' HREF = éé>"), Some("éé".to_owned()));
+        assert_eq!(href("<a data-href=\"x\" title=\"see href\">"), None);
+        assert_eq!(
+            href("<a title=\"see href=y\" href=\"x\">"),
+            Some("x".to_owned())
+        );
+        assert_eq!(href("<a download href>"), None);
+        assert_eq!(href("<a title=\"a>b\" href=\"x\">"), Some("x".to_owned()));
+        assert_eq!(href("<a title='a>b' href=x>"), Some("x".to_owned()));
+    }
+}
@@ -0,0 +1,800 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use clap::{ArgAction, Args};
+use log::debug;
+
+use crate::commands::lint::glossary::Glossary;
+use crate::commands::lint::length::MaxLengths;
+use crate::commands::lint::output::DiagnosticsReport;
+use crate::commands::report::write_to_output;
+use crate::config::{Config, current_config};
+use crate::options::{LengthUnit, OutputFormat, Severity, TermMatch, language_matches};
+use crate::tr;
+use crate::ts::{ContextNode, LocationNode, MessageNode, TSNode, TranslationType};
+
+mod accelerators;
+mod glossary;
+mod length;
+mod markup;
+pub(crate) mod output;
+mod placeholders;
+mod punctuation;
+
+/// Checks the translations of a file for common mistakes.
+#[derive(Args)]
+#[command(disable_help_flag = true)]
+pub struct LintArgs {
+    /// File path to lint translations from, or `@group` for a group of the configuration.
+    #[arg(help = tr!("cli-lint-input"), help_heading = tr!("cli-headers-arguments"))]
+    pub input_path: String,
+    /// Languages for which keyboard accelerators are not checked, e.g. `ja` or `zh_CN`.
+    #[arg(long, num_args = 1.., help = tr!("cli-lint-skip-accelerators"), help_heading = tr!("cli-headers-options"))]
+    pub skip_accelerators: Vec<String>,
+    /// Overrides the severity of rules, e.g. `ending-punctuation=error` or `capitalization=off`.
+    #[arg(short, long, num_args = 1.., value_name = "RULE=SEVERITY", value_parser = parse_rule_severity, help = tr!("cli-lint-severity"), help_heading = tr!("cli-headers-options"))]
+    pub severity: Vec<(String, Severity)>,
+    /// Tab separated file of maximum lengths: `context<TAB>id or source<TAB>limit`.
+    #[arg(long, help = tr!("cli-lint-max-length-file"), help_heading = tr!("cli-headers-options"))]
+    pub max_length_file: Option<String>,
+    /// Unit in which translations lengths are measured.
+    #[arg(long, value_enum, help = tr!("cli-lint-length-unit"), help_heading = tr!("cli-headers-options"))]
+    pub length_unit: Option<LengthUnit>,
+    /// Glossaries (Qt phrase books or tab separated files) of terms to enforce.
+    #[arg(short, long, num_args = 1.., help = tr!("cli-lint-glossary"), help_heading = tr!("cli-headers-options"))]
+    pub glossary: Vec<String>,
+    /// How glossary terms are matched against the words of messages.
+    #[arg(long, value_enum, help = tr!("cli-lint-glossary-match"), help_heading = tr!("cli-headers-options"))]
+    pub glossary_match: Option<TermMatch>,
+    /// Format in which problems are reported.
+    #[arg(short, long, value_enum, help = tr!("cli-lint-format"), help_heading = tr!("cli-headers-options"))]
+    pub format: Option<OutputFormat>,
+    /// If specified, will produce output in a file at designated location instead of stdout.
+    #[arg(short, long, help = tr!("cli-lint-output"), help_heading = tr!("cli-headers-options"))]
+    pub output_path: Option<String>,
+    #[arg(short, long, action = ArgAction::Help, help = tr!("cli-help"), help_heading = tr!("cli-headers-options"))]
+    pub help: Option<bool>,
+}
+
+/// Every lint rule along with its default severity.
+const RULES: [(&str, Severity); 9] = [
+    (placeholders::RULE, Severity::Error),
+    (accelerators::RULE, Severity::Error),
+    (markup::RULE, Severity::Error),
+    (punctuation::ENDING_PUNCTUATION_RULE, Severity::Warning),
+    (punctuation::SURROUNDING_WHITESPACE_RULE, Severity::Warning),
+    (punctuation::DOUBLE_SPACES_RULE, Severity::Warning),
+    (punctuation::CAPITALIZATION_RULE, Severity::Warning),
+    (length::RULE, Severity::Error),
+    (glossary::RULE, Severity::Warning),
+];
+
+/// Parses a `rule=severity` command line value.
+fn parse_rule_severity(value: &str) -> Result<(String, Severity), String> {
+    use clap::ValueEnum;
+
+    let (rule, severity) = value
+        .split_once('=')
+        .ok_or_else(|| tr!("cli-lint-severity-invalid", value = value))?;
+
+    known_rule(rule)?;
+
+    Severity::from_str(severity, true)
+        .map(|severity| (rule.to_owned(), severity))
+        .map_err(|_| tr!("cli-lint-severity-invalid", value = value))
+}
+
+/// Name of a lint rule, or an error listing the known rules.
+fn known_rule(rule: &str) -> Result<&'static str, String> {
+    RULES
+        .iter()
+        .map(|(name, _)| *name)
+        .find(|name| *name == rule)
+        .ok_or_else(|| {
+            tr!(
+                "cli-lint-severity-unknown-rule",
+                rule = rule,
+                rules = RULES.map(|(name, _)| name).join(", ")
+            )
+        })
+}
+
+/// Selects the lint rules to run on a file.
+struct LintOptions {
+    accelerators: bool,
+    severities: HashMap<&'static str, Severity>,
+    max_lengths: MaxLengths,
+    length_unit: LengthUnit,
+    glossary: Glossary,
+}
+
+impl Default for LintOptions {
+    fn default() -> Self {
+        LintOptions {
+            accelerators: true,
+            severities: HashMap::from(RULES),
+            max_lengths: MaxLengths::default(),
+            length_unit: LengthUnit::default(),
+            glossary: Glossary::default(),
+        }
+    }
+}
+
+impl LintOptions {
+    /// Options for linting a file, from the command line arguments then the project configuration.
+    fn new(args: &LintArgs, ts_node: &TSNode, config: &Config) -> Result<LintOptions, String> {
+        let language = ts_node.language.as_deref().unwrap_or_default();
+        let language_config = config.language(language);
+
+        let max_lengths = match args
+            .max_length_file
+            .as_ref()
+            .or(config.lint.max_length_file.as_ref())
+        {
+            None => MaxLengths::default(),
+            Some(path) => std::fs::read_to_string(path)
+                .map_err(|e| e.to_string())
+                .and_then(|content| MaxLengths::parse(&content))
+                .map_err(|e| {
+                    tr!(
+                        "error-open-or-parse",
+                        file = path.as_str(),
+                        error = e.to_string()
+                    )
+                })?,
+        };
+
+        let mut glossary = Glossary::new(
+            args.glossary_match
+                .or(config.lint.glossary_match)
+                .unwrap_or_default(),
+        );
+        let glossaries = match args.glossary.is_empty() {
+            true => &config.lint.glossary,
+            false => &args.glossary,
+        };
+        for path in glossaries {
+            glossary.load(path, language).map_err(|e| {
+                tr!(
+                    "error-open-or-parse",
+                    file = path.as_str(),
+                    error = e.to_string()
+                )
+            })?;
+        }
+
+        let skip_accelerators = match (
+            args.skip_accelerators.is_empty(),
+            language_config.and_then(|config| config.skip_accelerators),
+        ) {
+            (true, Some(skip)) => skip,
+            (true, None) => config
+                .lint
+                .skip_accelerators
+                .iter()
+                .any(|skipped| language_matches(language, skipped)),
+            (false, _) => args
+                .skip_accelerators
+                .iter()
+                .any(|skipped| language_matches(language, skipped)),
+        };
+
+        let mut options = LintOptions {
+            accelerators: !skip_accelerators,
+            max_lengths,
+            length_unit: args
+                .length_unit
+                .or(language_config.and_then(|config| config.length_unit))
+                .or(config.lint.length_unit)
+                .unwrap_or_default(),
+            glossary,
+            ..LintOptions::default()
+        };
+
+        let configured_severities = config
+            .lint
+            .severity
+            .iter()
+            .chain(language_config.iter().flat_map(|config| &config.severity));
+        for (rule, severity) in configured_severities.chain(
+            args.severity
+                .iter()
+                .map(|(rule, severity)| (rule, severity)),
+        ) {
+            *options
+                .severities
+                .get_mut(known_rule(rule)?)
+                .expect("Known rules have a severity") = *severity;
+        }
+
+        Ok(options)
+    }
+
+    fn severity(&self, rule: &str) -> Severity {
+        self.severities
+            .get(rule)
+            .copied()
+            .unwrap_or(Severity::Error)
+    }
+}
+
+/// Problem found by a lint rule on a translation message.
+#[derive(Debug, PartialEq)]
+pub struct Diagnostic {
+    /// Name of the rule that reported the problem.
+    pub rule: &'static str,
+    /// Severity of the rule: its default, or as set by `--severity` or the project configuration.
+    pub severity: Severity,
+    /// Name of the context the message belongs to.
+    pub context: String,
+    /// Source text of the message.
+    pub source: String,
+    /// First location of the message in the source code, if any.
+    pub location: Option<LocationNode>,
+    /// Description of the problem.
+    pub message: String,
+    /// Index of the context in the file, used to find the message in the TS file.
+    pub context_index: usize,
+    /// Index of the message in its context.
+    pub message_index: usize,
+}
+
+/// Message being linted, with the indexes locating it in the file.
+#[derive(Clone, Copy)]
+pub struct LintedMessage<'a> {
+    pub context: &'a ContextNode,
+    pub context_index: usize,
+    pub message: &'a MessageNode,
+    pub message_index: usize,
+}
+
+/// Messages of the context found at `context_index` in the file.
+pub fn linted_messages(
+    context_index: usize,
+    context: &ContextNode,
+) -> impl Iterator<Item = LintedMessage<'_>> {
+    context
+        .messages
+        .iter()
+        .enumerate()
+        .map(move |(message_index, message)| LintedMessage {
+            context,
+            context_index,
+            message,
+            message_index,
+        })
+}
+
+impl Diagnostic {
+    /// Creates the diagnostic of a rule for a message, unless the translator suppressed
+    /// that rule for the message.
+    pub fn for_message(
+        rule: &'static str,
+        linted_message: LintedMessage,
+        description: String,
+    ) -> Option<Diagnostic> {
+        let message = linted_message.message;
+        if is_suppressed(message, rule) {
+            debug!(
+                "Rule '{rule}' is suppressed for message {:?}",
+                message.source
+            );
+            return None;
+        }
+
+        Some(Diagnostic {
+            rule,
+            severity: Severity::Error,
+            context: linted_message.context.name.clone(),
+            source: message.source.clone().unwrap_or_default(),
+            location: message.locations.first().cloned(),
+            message: description,
+            context_index: linted_message.context_index,
+            message_index: linted_message.message_index,
+        })
+    }
+}
+
+/// Marker that translators can put in their comment to disable rules for a message, e.g.
+/// `lint-ignore: ending-punctuation, capitalization`. Without a list, every rule is disabled.
+const SUPPRESSION_MARKER: &str = "lint-ignore";
+
+fn is_suppressed(message: &MessageNode, rule: &str) -> bool {
+    let Some(comment) = message.translator_comment.as_deref() else {
+        return false;
+    };
+
+    comment
+        .match_indices(SUPPRESSION_MARKER)
+        .any(|(index, marker)| {
+            match comment[index + marker.len()..]
+                .trim_start()
+                .strip_prefix(':')
+            {
+                None => true,
+                Some(rules) => rules
+                    .lines()
+                    .next()
+                    .unwrap_or_default()
+                    .split(',')
+                    .any(|suppressed| suppressed.trim() == rule),
+            }
+        })
+}
+
+/// Diagnostic along with the file it was found in, printed as `file:line: severity: [rule] message`.
+/// When the message has no location, the translation file itself is reported.
+pub(crate) struct DiagnosticLine<'a> {
+    pub input_path: &'a str,
+    pub diagnostic: &'a Diagnostic,
+}
+
+impl Display for DiagnosticLine<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let diagnostic = self.diagnostic;
+        let location = diagnostic.location.as_ref();
+
+        match location.and_then(|location| location.filename.as_ref()) {
+            Some(filename) => write!(f, "{filename}")?,
+            None => write!(f, "{}", self.input_path)?,
+        }
+
+        if let Some(line) = location.and_then(|location| location.line) {
+            write!(f, ":{line}")?;
+        }
+
+        write!(
+            f,
+            ": {}: [{}] {} ({})",
+            diagnostic.severity,
+            diagnostic.rule,
+            diagnostic.message,
+            tr!(
+                "cli-lint-message-origin",
+                context = diagnostic.context.as_str(),
+                source = diagnostic.source.as_str()
+            )
+        )
+    }
+}
+
+/// Translated text of a message that is subject to linting.
+pub(crate) struct LintedText<'a> {
+    pub text: &'a str,
+    /// Index of the plural form, `None` for a simple translation.
+    pub numerus_index: Option<usize>,
+}
+
+impl LintedText<'_> {
+    /// Human-readable designation of the translation, used in diagnostics.
+    pub fn target(&self) -> String {
+        match self.numerus_index {
+            None => tr!("cli-lint-target-translation"),
+            Some(index) => {
+                let form_number = index + 1;
+                tr!("cli-lint-target-numerus", index = form_number)
+            }
+        }
+    }
+}
+
+/// Lists the non-empty translations of a message, including each numerus form.
+/// Obsolete and vanished translations are not shipped, therefore are not linted.
+pub(crate) fn linted_texts(message: &MessageNode) -> Vec<LintedText<'_>> {
+    let Some(translation) = message.translation.as_ref() else {
+        return vec![];
+    };
+
+    if matches!(
+        translation.translation_type,
+        Some(TranslationType::Obsolete) | Some(TranslationType::Vanished)
+    ) {
+        return vec![];
+    }
+
+    translation
+        .translation_simple
+        .iter()
+        .map(|text| LintedText {
+            text,
+            numerus_index: None,
+        })
+        .chain(
+            translation
+                .numerus_forms
+                .iter()
+                .enumerate()
+                .map(|(index, form)| LintedText {
+                    text: &form.text,
+                    numerus_index: Some(index),
+                }),
+        )
+        .filter(|text| !text.text.is_empty())
+        .collect()
+}
+
+/// Lints the input file and reports any problem found in the requested format.
+/// Returns an error when at least one error-level problem is found, so that it can be used in CI.
+pub fn lint_main(args: &LintArgs) -> Result<(), String> {
+    let config = current_config();
+    let mut report = DiagnosticsReport::default();
+
+    for input_path in config.expand_input(&args.input_path)? {
+        let (content, ts_node) = load_file(&input_path)?;
+
+        let options = LintOptions::new(args, &ts_node, config)?;
+        report.add_file(&input_path, &content, lint_ts_node(&ts_node, &options));
+    }
+
+    let output = report.format(args.format.or(config.lint.format).unwrap_or_default())?;
+
+    match &args.output_path {
+        None => print!("{output}"),
+        Some(output_path) => write_to_output(output_path, &output)?,
+    }
+
+    let errors = report
+        .diagnostics()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .count();
+
+    if errors == 0 {
+        Ok(())
+    } else {
+        Err(tr!(
+            "cli-lint-problems-found",
+            count = errors,
+            file = args.input_path.as_str()
+        ))
+    }
+}
+
+/// Runs every selected lint rule on every context of the file.
+fn lint_ts_node(ts_node: &TSNode, options: &LintOptions) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    for (index, context) in ts_node.contexts.iter().enumerate() {
+        diagnostics.extend(placeholders::check_placeholders(index, context));
+        diagnostics.extend(markup::check_markup(index, context));
+        diagnostics.extend(punctuation::check_punctuation(index, context));
+        diagnostics.extend(length::check_max_length(index, context, options));
+        diagnostics.extend(glossary::check_glossary(index, context, &options.glossary));
+
+        if options.accelerators {
+            diagnostics.extend(accelerators::check_accelerators(index, context));
+        }
+    }
+
+    diagnostics.retain_mut(|diagnostic| {
+        diagnostic.severity = options.severity(diagnostic.rule);
+        diagnostic.severity != Severity::Off
+    });
+
+    debug!("Lint found {} problems", diagnostics.len());
+
+    diagnostics
+}
+
+/// Loads a TS file, also returning its content to locate messages in it.
+pub(crate) fn load_file(path: &str) -> Result<(String, TSNode), String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| tr!("error-open-or-parse", file = path, error = e.to_string()))?;
+
+    let ts_node = quick_xml::de::from_str(&content)
+        .map_err(|e| tr!("error-ts-file-parse", file = path, error = e.to_string()))?;
+
+    Ok((content, ts_node))
+}
+
+#[cfg(test)]
+mod lint_test {
+    use super::*;
+    use crate::commands::test_utils::load_test_file;
+
+    /// Options running only the given rules, with their default severity.
+    fn options_with_rules(rules: &[&str]) -> LintOptions {
+        let mut options = LintOptions::default();
+        options
+            .severities
+            .iter_mut()
+            .filter(|(rule, _)| !rules.contains(rule))
+            .for_each(|(_, severity)| *severity = Severity::Off);
+        options
+    }
+
+    #[test]
+    fn test_lint_placeholders() {
+        let data = load_test_file("example_lint_placeholders.xml");
+
+        let diagnostics = lint_ts_node(&data, &options_with_rules(&[placeholders::RULE]));
+        let lines: Vec<String> = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                DiagnosticLine {
+                    input_path: "example_lint_placeholders.xml",
+                    diagnostic,
+                }
+                .to_string()
+            })
+            .collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "dialog.cpp:12: error: [placeholders] Placeholder %2 is missing from the translation. (context: Dialog, source: \"Copying %1 to %2\")",
+                "dialog.cpp:20: error: [placeholders] Placeholder %1 is used more often in the translation than in the source. (context: Dialog, source: \"Delete %1?\")",
+                "dialog.cpp:25: error: [placeholders] Placeholder %3 in numerus form 2 is beyond the highest source placeholder %2. (context: Dialog, source: \"%n file(s) in %1 and %2\")",
+                "example_lint_placeholders.xml: error: [placeholders] Placeholder %1 in the translation does not exist in the source. (context: Dialog, source: \"Done %2\")",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_output_formats() {
+        let path = "./test_data/example_lint_placeholders.xml";
+        let (content, data) = load_file(path).expect("Loadable");
+        let mut report = DiagnosticsReport::default();
+        report.add_file(
+            path,
+            &content,
+            lint_ts_node(&data, &options_with_rules(&[placeholders::RULE])),
+        );
+
+        let github = report.format(OutputFormat::Github).expect("Formattable");
+        assert_eq!(
+            github.lines().next(),
+            Some(
+                "::error file=./test_data/example_lint_placeholders.xml,line=6,title=placeholders::Placeholder %252 is missing from the translation. (context: Dialog, source: \"Copying %251 to %252\")"
+            )
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&report.format(OutputFormat::Json).expect("Formattable"))
+                .expect("Valid JSON");
+        assert_eq!(json[0]["line"], 6);
+        assert_eq!(json[0]["location"]["file"], "./test_data/dialog.cpp");
+        assert_eq!(json[0]["location"]["line"], 12);
+        assert_eq!(json[3]["line"], 29);
+        assert!(json[3]["location"].is_null());
+
+        let sarif: serde_json::Value =
+            serde_json::from_str(&report.format(OutputFormat::Sarif).expect("Formattable"))
+                .expect("Valid JSON");
+        let result = &sarif["runs"][0]["results"][1];
+        assert_eq!(sarif["version"], "2.1.0");
+        assert_eq!(result["ruleId"], "placeholders");
+        assert_eq!(result["level"], "error");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["region"]["startLine"],
+            16
+        );
+        assert_eq!(
+            result["relatedLocations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "./test_data/dialog.cpp"
+        );
+    }
+
+    #[test]
+    fn test_lint_options_from_config() {
+        let config_content =
+            std::fs::read_to_string("./test_data/config/qt-ts-tools.toml").expect("Readable");
+        let config = Config::parse(&config_content, std::path::Path::new("./test_data/config"))
+            .expect("Parsable");
+        let mut args = LintArgs {
+            input_path: "@app".to_owned(),
+            skip_accelerators: vec![],
+            severity: vec![],
+            max_length_file: None,
+            length_unit: None,
+            glossary: vec![],
+            glossary_match: None,
+            format: None,
+            output_path: None,
+            help: None,
+        };
+
+        let inputs = config.expand_input(&args.input_path).expect("Known group");
+        let (_, german) = load_file(&inputs[0]).expect("Loadable");
+        let (_, japanese) = load_file(&inputs[1]).expect("Loadable");
+
+        let options = LintOptions::new(&args, &german, &config).expect("Valid options");
+        assert!(options.accelerators);
+        assert_eq!(options.severity("capitalization"), Severity::Off);
+        assert_eq!(options.severity("ending-punctuation"), Severity::Error);
+
+        let options = LintOptions::new(&args, &japanese, &config).expect("Valid options");
+        assert!(!options.accelerators);
+
+        // Command line arguments take precedence over the configuration
+        args.skip_accelerators = vec!["de".to_owned()];
+        args.severity = vec![("capitalization".to_owned(), Severity::Warning)];
+        let options = LintOptions::new(&args, &japanese, &config).expect("Valid options");
+        assert!(options.accelerators);
+        assert_eq!(options.severity("capitalization"), Severity::Warning);
+    }
+
+    #[test]
+    fn test_lint_accelerators() {
+        let data = load_test_file("example_lint_accelerators.xml");
+
+        let diagnostics = lint_ts_node(&data, &options_with_rules(&[accelerators::RULE]));
+        let messages: Vec<(&str, &str)> = diagnostics
+            .iter()
+            .map(|diagnostic| (diagnostic.source.as_str(), diagnostic.message.as_str()))
+            .collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    "&Open",
+                    "Keyboard accelerator (&) of the source is missing from the translation."
+                ),
+                (
+                    "Save",
+                    "Keyboard accelerator (&) in the translation does not exist in the source."
+                ),
+                (
+                    "&Quit",
+                    "Mnemonic \"d\" is already used by \"&Datei\" in the same context."
+                ),
+            ]
+        );
+
+        let options = LintOptions {
+            accelerators: false,
+            ..options_with_rules(&[accelerators::RULE])
+        };
+        assert!(lint_ts_node(&data, &options).is_empty());
+    }
+
+    #[test]
+    fn test_lint_markup() {
+        let data = load_test_file("example_lint_markup.xml");
+
+        let diagnostics = lint_ts_node(&data, &options_with_rules(&[markup::RULE]));
+        let messages: Vec<&str> = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.message.as_str())
+            .collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "Tag <i> of the source is missing from the translation.",
+                "Tag <b> in the translation does not exist in the source.",
+                "Tag <b> is not properly closed in the translation.",
+                "Link target \"https://qt.io\" of the source is missing from the translation.",
+                "Tag <br> in numerus form 2 does not exist in the source.",
+                "Entity &nbsp; of the source is missing from numerus form 2.",
+                "Link target \"https://qt.io\" of the source is missing from the translation.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_punctuation() {
+        let data = load_test_file("example_lint_punctuation.xml");
+
+        let diagnostics = lint_ts_node(
+            &data,
+            &options_with_rules(&[
+                punctuation::ENDING_PUNCTUATION_RULE,
+                punctuation::SURROUNDING_WHITESPACE_RULE,
+                punctuation::DOUBLE_SPACES_RULE,
+                punctuation::CAPITALIZATION_RULE,
+            ]),
+        );
+        let messages: Vec<(&str, Severity, &str)> = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                (
+                    diagnostic.rule,
+                    diagnostic.severity,
+                    diagnostic.message.as_str(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                (
+                    punctuation::ENDING_PUNCTUATION_RULE,
+                    Severity::Warning,
+                    "Ending punctuation \":\" of the source is missing from the translation."
+                ),
+                (
+                    punctuation::ENDING_PUNCTUATION_RULE,
+                    Severity::Warning,
+                    "Ending punctuation \".\" in the translation differs from \"...\" in the source."
+                ),
+                (
+                    punctuation::SURROUNDING_WHITESPACE_RULE,
+                    Severity::Warning,
+                    "Leading whitespace of the translation differs from the source."
+                ),
+                (
+                    punctuation::SURROUNDING_WHITESPACE_RULE,
+                    Severity::Warning,
+                    "Trailing whitespace of the translation differs from the source."
+                ),
+                (
+                    punctuation::DOUBLE_SPACES_RULE,
+                    Severity::Warning,
+                    "Doubled spaces in the translation."
+                ),
+                (
+                    punctuation::CAPITALIZATION_RULE,
+                    Severity::Warning,
+                    "Lowercase start in the translation while the source starts with an uppercase letter."
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rule_severity() {
+        assert_eq!(
+            parse_rule_severity("capitalization=error"),
+            Ok(("capitalization".to_owned(), Severity::Error))
+        );
+        assert!(parse_rule_severity("capitalization").is_err());
+        assert!(parse_rule_severity("capitalization=fatal").is_err());
+        assert!(parse_rule_severity("unknown=off").is_err());
+    }
+
+    #[test]
+    fn test_lint_max_length() {
+        let data = load_test_file("example_lint_max_length.xml");
+        let mut options = options_with_rules(&[length::RULE]);
+        options.max_lengths =
+            MaxLengths::parse("Toolbar\tPrint\t6\nToolbar\ttoolbar-quit\t4\n").expect("Parsable");
+
+        let messages: Vec<String> = lint_ts_node(&data, &options)
+            .into_iter()
+            .map(|diagnostic| diagnostic.message)
+            .collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "Length of the translation is 10 characters, over the limit of 8.",
+                "Length of numerus form 2 is 11 characters, over the limit of 10.",
+                "Length of the translation is 7 characters, over the limit of 6.",
+                "Length of the translation is 7 characters, over the limit of 4.",
+            ]
+        );
+
+        options.length_unit = LengthUnit::Utf16;
+        assert_eq!(lint_ts_node(&data, &options).len(), 5);
+    }
+
+    #[test]
+    fn test_lint_glossary() {
+        let data = load_test_file("example_lint_glossary.xml");
+        let mut options = options_with_rules(&[glossary::RULE]);
+        options
+            .glossary
+            .load("./test_data/example_glossary.qph", "de_DE")
+            .expect("Phrase book is loadable");
+        options
+            .glossary
+            .load("./test_data/example_glossary.tsv", "de_DE")
+            .expect("TSV glossary is loadable");
+
+        let messages: Vec<String> = lint_ts_node(&data, &options)
+            .into_iter()
+            .map(|diagnostic| diagnostic.message)
+            .collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "Glossary term \"Workspace\" should be translated as \"Arbeitsbereich\" in the translation.",
+                "Glossary term \"Save as\" should be translated as \"Speichern unter\" in the translation.",
+                "Glossary term \"Workspace\" should be translated as \"Arbeitsbereich\" in numerus form 2.",
+            ]
+        );
+    }
+}
@@ -0,0 +1,350 @@
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::commands::lint::{Diagnostic, DiagnosticLine};
+use crate::commands::report::to_json;
+use crate::options::{OutputFormat, Severity};
+use crate::tr;
+
+/// Diagnostics found in TS files, ready to be formatted.
+#[derive(Default)]
+pub struct DiagnosticsReport {
+    files: Vec<FileDiagnostics>,
+}
+
+/// Diagnostics found in a TS file.
+struct FileDiagnostics {
+    input_path: String,
+    diagnostics: Vec<Diagnostic>,
+    /// Line of each message of the TS file, by context then by message.
+    message_lines: Vec<Vec<u64>>,
+}
+
+impl FileDiagnostics {
+    /// Line of the message of a diagnostic in the TS file.
+    fn ts_line(&self, diagnostic: &Diagnostic) -> Option<u64> {
+        self.message_lines
+            .get(diagnostic.context_index)?
+            .get(diagnostic.message_index)
+            .copied()
+    }
+
+    /// Location of the message in the source code. Paths of TS files are relative to the TS file.
+    fn source_location(&self, diagnostic: &Diagnostic) -> Option<(String, Option<u32>)> {
+        let location = diagnostic.location.as_ref()?;
+        let filename = location.filename.as_ref()?;
+        let path = Path::new(&self.input_path)
+            .parent()
+            .unwrap_or(Path::new(""))
+            .join(filename);
+
+        Some((to_uri(&path.to_string_lossy()), location.line))
+    }
+}
+
+impl DiagnosticsReport {
+    /// Adds the diagnostics found in a TS file, whose `content` is used to locate messages in it.
+    pub fn add_file(&mut self, input_path: &str, content: &str, diagnostics: Vec<Diagnostic>) {
+        self.files.push(FileDiagnostics {
+            input_path: input_path.to_owned(),
+            diagnostics,
+            message_lines: message_lines(content),
+        });
+    }
+
+    /// Diagnostics of every file.
+    pub fn diagnostics(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.entries().map(|(_, diagnostic)| diagnostic)
+    }
+
+    pub fn format(&self, format: OutputFormat) -> Result<String, String> {
+        match format {
+            OutputFormat::Text => Ok(self.to_text()),
+            OutputFormat::Json => to_json(&self.to_json_diagnostics()),
+            OutputFormat::Sarif => to_json(&self.to_sarif()),
+            OutputFormat::Github => Ok(self.to_github()),
+        }
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (&FileDiagnostics, &Diagnostic)> {
+        self.files.iter().flat_map(|file| {
+            file.diagnostics
+                .iter()
+                .map(move |diagnostic| (file, diagnostic))
+        })
+    }
+
+    fn to_text(&self) -> String {
+        self.entries()
+            .map(|(file, diagnostic)| {
+                format!(
+                    "{}\n",
+                    DiagnosticLine {
+                        input_path: &file.input_path,
+                        diagnostic
+                    }
+                )
+            })
+            .collect()
+    }
+
+    fn to_json_diagnostics(&self) -> Vec<JsonDiagnostic<'_>> {
+        self.entries()
+            .map(|(file, diagnostic)| JsonDiagnostic {
+                rule: diagnostic.rule,
+                severity: diagnostic.severity.to_string(),
+                message: &diagnostic.message,
+                context: &diagnostic.context,
+                source: &diagnostic.source,
+                file: &file.input_path,
+                line: file.ts_line(diagnostic),
+                location: file
+                    .source_location(diagnostic)
+                    .map(|(file, line)| JsonLocation { file, line }),
+            })
+            .collect()
+    }
+
+    fn to_sarif(&self) -> SarifLog<'_> {
+        let mut rules: Vec<&str> = self
+            .diagnostics()
+            .map(|diagnostic| diagnostic.rule)
+            .collect();
+        rules.sort_unstable();
+        rules.dedup();
+
+        let results = self
+            .entries()
+            .map(|(file, diagnostic)| SarifResult {
+                rule_id: diagnostic.rule,
+                level: diagnostic.severity.to_string(),
+                message: SarifMessage {
+                    text: format!(
+                        "{} ({})",
+                        diagnostic.message,
+                        tr!(
+                            "cli-lint-message-origin",
+                            context = diagnostic.context.as_str(),
+                            source = diagnostic.source.as_str()
+                        )
+                    ),
+                },
+                locations: vec![SarifLocation::new(
+                    to_uri(&file.input_path),
+                    file.ts_line(diagnostic),
+                )],
+                related_locations: file
+                    .source_location(diagnostic)
+                    .map(|(file, line)| SarifLocation::new(file, line.map(u64::from)))
+                    .into_iter()
+                    .collect(),
+            })
+            .collect();
+
+        SarifLog {
+            schema: "https://json.schemastore.org/sarif-2.1.0.json",
+            version: "2.1.0",
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: env!("CARGO_PKG_NAME"),
+                        version: env!("CARGO_PKG_VERSION"),
+                        information_uri: env!("CARGO_PKG_REPOSITORY"),
+                        rules: rules.into_iter().map(|id| SarifRule { id }).collect(),
+                    },
+                },
+                results,
+            }],
+        }
+    }
+
+    /// GitHub annotations are attached to the TS file, as it is the file changed by translators.
+    fn to_github(&self) -> String {
+        self.entries()
+            .map(|(file, diagnostic)| {
+                let command = match diagnostic.severity {
+                    Severity::Error => "error",
+                    _ => "warning",
+                };
+                let line = file
+                    .ts_line(diagnostic)
+                    .map(|line| format!(",line={line}"))
+                    .unwrap_or_default();
+                let message = format!(
+                    "{} ({})",
+                    diagnostic.message,
+                    tr!(
+                        "cli-lint-message-origin",
+                        context = diagnostic.context.as_str(),
+                        source = diagnostic.source.as_str()
+                    )
+                );
+
+                format!(
+                    "::{command} file={}{line},title={}::{}\n",
+                    escape_github_property(&file.input_path),
+                    escape_github_property(diagnostic.rule),
+                    escape_github_data(&message)
+                )
+            })
+            .collect()
+    }
+}
+
+/// Finds the line of every `<message>` of a TS file, grouped by `<context>`, in document order.
+fn message_lines(content: &str) -> Vec<Vec<u64>> {
+    use quick_xml::events::Event;
+
+    let mut reader = quick_xml::Reader::from_str(content);
+    let mut lines: Vec<Vec<u64>> = vec![];
+    // Positions only increase, so newlines are counted from the previous message on
+    let (mut last_position, mut line) = (0, 1);
+
+    loop {
+        let position = reader.buffer_position() as usize;
+
+        match reader.read_event() {
+            Ok(Event::Start(tag)) if tag.name().as_ref() == b"context" => lines.push(vec![]),
+            Ok(Event::Start(tag) | Event::Empty(tag)) if tag.name().as_ref() == b"message" => {
+                let position = position.min(content.len());
+                line += content.as_bytes()[last_position..position]
+                    .iter()
+                    .filter(|byte| **byte == b'\n')
+                    .count() as u64;
+                last_position = position;
+
+                if let Some(context) = lines.last_mut() {
+                    context.push(line);
+                }
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+
+    lines
+}
+
+fn to_uri(path: &str) -> String {
+    path.replace('\\', "/")
+}
+
+/// Escapes the message of a GitHub workflow command.
+fn escape_github_data(data: &str) -> String {
+    data.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes a property (e.g. `file=...`) of a GitHub workflow command.
+fn escape_github_property(property: &str) -> String {
+    escape_github_data(property)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    rule: &'a str,
+    severity: String,
+    message: &'a str,
+    context: &'a str,
+    source: &'a str,
+    /// TS file and line of the message in it
+    file: &'a str,
+    line: Option<u64>,
+    /// Location of the message in the source code
+    location: Option<JsonLocation>,
+}
+
+#[derive(Serialize)]
+struct JsonLocation {
+    file: String,
+    line: Option<u32>,
+}
+
+#[derive(Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'a str,
+    version: &'a str,
+    runs: Vec<SarifRun<'a>>,
+}
+
+#[derive(Serialize)]
+struct SarifRun<'a> {
+    tool: SarifTool<'a>,
+    results: Vec<SarifResult<'a>>,
+}
+
+#[derive(Serialize)]
+struct SarifTool<'a> {
+    driver: SarifDriver<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver<'a> {
+    name: &'a str,
+    version: &'a str,
+    information_uri: &'a str,
+    rules: Vec<SarifRule<'a>>,
+}
+
+#[derive(Serialize)]
+struct SarifRule<'a> {
+    id: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    rule_id: &'a str,
+    level: String,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<SarifLocation>,
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+impl SarifLocation {
+    fn new(uri: String, line: Option<u64>) -> Self {
+        SarifLocation {
+            physical_location: SarifPhysicalLocation {
+                artifact_location: SarifArtifactLocation { uri },
+                region: line.map(|start_line| SarifRegion { start_line }),
+            },
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: u64,
+}
@@ -0,0 +1,179 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use crate::tr;
+use crate::ts::ContextNode;
+
+use super::{Diagnostic, linted_messages, linted_texts};
+
+pub const RULE: &str = "placeholders";
+
+/// Placeholder understood by `QString::arg` (`%1` to `%99`) and by `QObject::tr` numerus
+/// messages (`%n`). The localized variants (`%L1`, `%Ln`) are considered equivalent
+/// to their non-localized counterparts since the translator may choose either.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Placeholder {
+    Numerus,
+    Arg(u8),
+}
+
+impl Display for Placeholder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Placeholder::Numerus => write!(f, "%n"),
+            Placeholder::Arg(number) => write!(f, "%{number}"),
+        }
+    }
+}
+
+/// Lists every placeholder of `text`, in order of appearance.
+pub fn parse_placeholders(text: &str) -> Vec<Placeholder> {
+    let mut placeholders = vec![];
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            continue;
+        }
+
+        if chars.peek() == Some(&'L') {
+            chars.next();
+        }
+
+        match chars.peek() {
+            Some('n') => {
+                chars.next();
+                placeholders.push(Placeholder::Numerus);
+            }
+            Some(digit) if digit.is_ascii_digit() => {
+                let mut number = chars.next().and_then(|d| d.to_digit(10)).unwrap_or(0);
+                if let Some(second) = chars.peek().and_then(|d| d.to_digit(10)) {
+                    chars.next();
+                    number = number * 10 + second;
+                }
+
+                // %0 is not a valid placeholder for `QString::arg`
+                if number > 0 {
+                    placeholders.push(Placeholder::Arg(number as u8));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    placeholders
+}
+
+fn count_placeholders(text: &str) -> BTreeMap<Placeholder, usize> {
+    let mut counts = BTreeMap::new();
+    for placeholder in parse_placeholders(text) {
+        *counts.entry(placeholder).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Verifies that every translation (simple or numerus forms) uses the same placeholders as its source:
+/// * A placeholder from the source is missing from the translation;
+/// * A placeholder is used more often in the translation than in the source;
+/// * A placeholder that does not exist in the source is used in the translation.
+///
+/// Numerus forms are allowed to omit `%n`, as it is common practice for singular forms ("one file").
+pub fn check_placeholders(context_index: usize, context: &ContextNode) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    for linted_message in linted_messages(context_index, context) {
+        let message = linted_message.message;
+        let Some(source) = message.source.as_ref() else {
+            continue;
+        };
+
+        let source_counts = count_placeholders(source);
+        let highest_arg = source_counts
+            .keys()
+            .filter_map(|placeholder| match placeholder {
+                Placeholder::Arg(number) => Some(*number),
+                Placeholder::Numerus => None,
+            })
+            .max()
+            .unwrap_or(0);
+
+        for text in linted_texts(message) {
+            let translation_counts = count_placeholders(text.text);
+            let target = text.target();
+
+            for (placeholder, source_count) in &source_counts {
+                let translation_count = translation_counts.get(placeholder).copied().unwrap_or(0);
+
+                if translation_count == 0 {
+                    if *placeholder == Placeholder::Numerus && text.numerus_index.is_some() {
+                        continue;
+                    }
+
+                    diagnostics.extend(Diagnostic::for_message(
+                        RULE,
+                        linted_message,
+                        tr!(
+                            "cli-lint-placeholder-missing",
+                            placeholder = placeholder.to_string(),
+                            target = target.clone()
+                        ),
+                    ));
+                } else if translation_count > *source_count {
+                    diagnostics.extend(Diagnostic::for_message(
+                        RULE,
+                        linted_message,
+                        tr!(
+                            "cli-lint-placeholder-duplicated",
+                            placeholder = placeholder.to_string(),
+                            target = target.clone()
+                        ),
+                    ));
+                }
+            }
+
+            for placeholder in translation_counts.keys() {
+                if source_counts.contains_key(placeholder) {
+                    continue;
+                }
+
+                let message_text = match placeholder {
+                    Placeholder::Arg(number) if highest_arg > 0 && *number > highest_arg => tr!(
+                        "cli-lint-placeholder-out-of-range",
+                        placeholder = placeholder.to_string(),
+                        target = target.clone(),
+                        highest = format!("%{highest_arg}")
+                    ),
+                    _ => tr!(
+                        "cli-lint-placeholder-unexpected",
+                        placeholder = placeholder.to_string(),
+                        target = target.clone()
+                    ),
+                };
+
+                diagnostics.extend(Diagnostic::for_message(RULE, linted_message, message_text));
+            }
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod placeholders_test {
+    use super::*;
+
+    #[test]
+    fn test_parse_placeholders() {
+        assert_eq!(
+            parse_placeholders("%1 of %L2 files, %n left, 100% done, %12%Ln %0"),
+            vec![
+                Placeholder::Arg(1),
+                Placeholder::Arg(2),
+                Placeholder::Numerus,
+                Placeholder::Arg(12),
+                Placeholder::Numerus
+            ]
+        );
+        assert_eq!(parse_placeholders("No placeholder"), vec![]);
+    }
+}
@@ -0,0 +1,154 @@
+use crate::tr;
+use crate::ts::ContextNode;
+
+use super::{Diagnostic, linted_messages, linted_texts};
+
+pub const ENDING_PUNCTUATION_RULE: &str = "ending-punctuation";
+pub const SURROUNDING_WHITESPACE_RULE: &str = "surrounding-whitespace";
+pub const DOUBLE_SPACES_RULE: &str = "double-spaces";
+pub const CAPITALIZATION_RULE: &str = "capitalization";
+
+/// Punctuation that may terminate a sentence or a label, including full-width variants.
+const ENDING_PUNCTUATION: [char; 11] = ['.', ':', '!', '?', ';', '…', '。', '：', '！', '？', '；'];
+
+/// Returns the punctuation ending `text`, normalized so that language specific forms compare
+/// equal: full-width characters are mapped to their ASCII counterpart and `…` to `...`.
+/// Spaces before the punctuation (e.g. French `Nom :`) are not considered.
+fn ending_punctuation(text: &str) -> String {
+    let trimmed = text.trim_end();
+    let start = trimmed
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| ENDING_PUNCTUATION.contains(c))
+        .last()
+        .map(|(index, _)| index)
+        .unwrap_or(trimmed.len());
+
+    trimmed[start..]
+        .chars()
+        .map(|c| match c {
+            '…' => "...".to_owned(),
+            '。' => ".".to_owned(),
+            '：' => ":".to_owned(),
+            '！' => "!".to_owned(),
+            '？' => "?".to_owned(),
+            '；' => ";".to_owned(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+fn leading_whitespace(text: &str) -> &str {
+    &text[..text.len() - text.trim_start().len()]
+}
+
+fn trailing_whitespace(text: &str) -> &str {
+    &text[text.trim_end().len()..]
+}
+
+/// Verifies the typography of translations compared to their source:
+/// * `ending-punctuation`: the translation ends with the same punctuation (`:`, `...`, `?`...);
+/// * `surrounding-whitespace`: leading and trailing whitespaces are kept as is;
+/// * `double-spaces`: no doubled space is introduced;
+/// * `capitalization`: the translation does not start lowercase when the source starts uppercase.
+pub fn check_punctuation(context_index: usize, context: &ContextNode) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    for linted_message in linted_messages(context_index, context) {
+        let message = linted_message.message;
+        let Some(source) = message.source.as_ref() else {
+            continue;
+        };
+
+        let source_ending = ending_punctuation(source);
+        let source_starts_uppercase = source
+            .trim_start()
+            .chars()
+            .next()
+            .is_some_and(char::is_uppercase);
+
+        for text in linted_texts(message) {
+            let target = text.target();
+            let mut report = |rule, description| {
+                diagnostics.extend(Diagnostic::for_message(rule, linted_message, description))
+            };
+
+            let ending = ending_punctuation(text.text);
+            if ending != source_ending {
+                report(
+                    ENDING_PUNCTUATION_RULE,
+                    match (source_ending.is_empty(), ending.is_empty()) {
+                        (false, true) => tr!(
+                            "cli-lint-ending-punctuation-missing",
+                            punctuation = source_ending.as_str(),
+                            target = target.clone()
+                        ),
+                        (true, false) => tr!(
+                            "cli-lint-ending-punctuation-added",
+                            punctuation = ending.as_str(),
+                            target = target.clone()
+                        ),
+                        _ => tr!(
+                            "cli-lint-ending-punctuation-changed",
+                            punctuation = ending.as_str(),
+                            expected = source_ending.as_str(),
+                            target = target.clone()
+                        ),
+                    },
+                );
+            }
+
+            if leading_whitespace(text.text) != leading_whitespace(source) {
+                report(
+                    SURROUNDING_WHITESPACE_RULE,
+                    tr!("cli-lint-leading-whitespace", target = target.clone()),
+                );
+            }
+
+            if trailing_whitespace(text.text) != trailing_whitespace(source) {
+                report(
+                    SURROUNDING_WHITESPACE_RULE,
+                    tr!("cli-lint-trailing-whitespace", target = target.clone()),
+                );
+            }
+
+            if text.text.trim().contains("  ") && !source.trim().contains("  ") {
+                report(
+                    DOUBLE_SPACES_RULE,
+                    tr!("cli-lint-double-spaces", target = target.clone()),
+                );
+            }
+
+            if source_starts_uppercase
+                && text
+                    .text
+                    .trim_start()
+                    .chars()
+                    .next()
+                    .is_some_and(char::is_lowercase)
+            {
+                report(
+                    CAPITALIZATION_RULE,
+                    tr!("cli-lint-capitalization", target = target.clone()),
+                );
+            }
+        }
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod punctuation_test {
+    use super::*;
+
+    #[test]
+    fn test_ending_punctuation() {
+        assert_eq!(ending_punctuation("Name:"), ":");
+        assert_eq!(ending_punctuation("Nom :"), ":");
+        assert_eq!(ending_punctuation("Open…"), "...");
+        assert_eq!(ending_punctuation("Open... "), "...");
+        assert_eq!(ending_punctuation("名前："), ":");
+        assert_eq!(ending_punctuation("Cancel"), "");
+    }
+}
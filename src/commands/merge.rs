@@ -1,25 +1,58 @@
-use std::hash::{Hash, Hasher};
+use std::collections::HashSet;
 
 use clap::{ArgAction, Args};
 use log::debug;
 
+use crate::commands::merge_report::MergeReport;
+use crate::commands::report::write_to_output;
+use crate::commands::text::translation_text;
+use crate::config::{Config, current_config};
 use crate::locale::tr;
+use crate::options::{MatchStrategy, ReportFormat};
 use crate::ts;
-use crate::ts::{MessageNode, TSNode};
+use crate::ts::{ContextNode, MessageNode, TSNode, TranslationNode, TranslationType};
 
-/// Merges two translation file contexts and messages into a single output.
+/// Merges translation files contexts and messages into a single output.
 #[derive(Args)]
 #[command(disable_help_flag = true)]
 pub struct MergeArgs {
     /// File to receive the merge
     #[arg(help = tr!("cli-merge-input-left"), help_heading = tr!("cli-headers-arguments"))]
     pub input_left: String,
-    /// File to include changes from
-    #[arg(help = tr!("cli-merge-input-right"), help_heading = tr!("cli-headers-arguments"))]
-    pub input_right: String,
+    /// Files to include changes from, in order: each one takes precedence over the previous ones.
+    #[arg(required = true, num_args = 1.., help = tr!("cli-merge-input-right"), help_heading = tr!("cli-headers-arguments"))]
+    pub inputs_right: Vec<String>,
+    /// How messages of both files are identified as being the same message.
+    #[arg(short, long, value_enum, help = tr!("cli-merge-strategy"), help_heading = tr!("cli-headers-options"))]
+    pub strategy: Option<MatchStrategy>,
+    /// Similarity, from 0 to 1, above which a new source takes the translation of the closest vanished source.
+    #[arg(long, value_name = "RATIO", value_parser = parse_fuzzy_threshold, help = tr!("cli-merge-fuzzy-threshold"), help_heading = tr!("cli-headers-options"))]
+    pub fuzzy_threshold: Option<f64>,
     /// When true, do not update the translation value.
-    #[arg(help = tr!("cli-merge-keep-translation"), help_heading = tr!("cli-headers-arguments"), action = ArgAction::SetTrue)]
+    #[arg(long, action = ArgAction::SetTrue, help = tr!("cli-merge-keep-translation"), help_heading = tr!("cli-headers-options"))]
     pub keep_translation: bool,
+    /// Common ancestor of both files, for a three-way merge taking changes from both sides.
+    #[arg(short, long, conflicts_with = "keep_translation", help = tr!("cli-merge-base"), help_heading = tr!("cli-headers-options"))]
+    pub base: Option<String>,
+    /// Takes the right file as the complete set of current sources: the left messages missing from it
+    /// are marked vanished or obsolete, and translations are kept.
+    #[arg(short, long, action = ArgAction::SetTrue, conflicts_with = "base", help = tr!("cli-merge-update"), help_heading = tr!("cli-headers-options"))]
+    pub update: bool,
+    /// Removes the vanished and obsolete messages instead of keeping them.
+    #[arg(long, action = ArgAction::SetTrue, requires = "update", conflicts_with = "provenance_report", help = tr!("cli-merge-no-obsolete"), help_heading = tr!("cli-headers-options"))]
+    pub no_obsolete: bool,
+    /// Prints the changes the merge would make to the left file instead of the merged file.
+    #[arg(long, action = ArgAction::SetTrue, conflicts_with_all = ["base", "provenance_report"], help = tr!("cli-merge-dry-run"), help_heading = tr!("cli-headers-options"))]
+    pub dry_run: bool,
+    /// Format of the report of `--dry-run`.
+    #[arg(long, value_enum, requires = "dry_run", help = tr!("cli-merge-report-format"), help_heading = tr!("cli-headers-options"))]
+    pub report_format: Option<ReportFormat>,
+    /// File listing the input from which each translation of the merged file comes.
+    #[arg(long, conflicts_with = "base", help = tr!("cli-merge-provenance-report"), help_heading = tr!("cli-headers-options"))]
+    pub provenance_report: Option<String>,
+    /// File listing the messages changed differently on both sides of a three-way merge.
+    #[arg(long, requires = "base", help = tr!("cli-merge-conflict-report"), help_heading = tr!("cli-headers-options"))]
+    pub conflict_report: Option<String>,
     /// If specified, will produce output in a file at designated location instead of stdout.
     #[arg(short, long, help = tr!("cli-merge-output"), help_heading = tr!("cli-headers-options"))]
     pub output_path: Option<String>,
@@ -27,221 +60,870 @@ pub struct MergeArgs {
     pub help: Option<bool>,
 }
 
-// This works by depending on cmp looking up only source and location on messages nodes
-// and on context by comparing the names only
+// Contexts are matched by name, messages by the selected strategy
 pub fn merge_main(args: &MergeArgs) -> Result<(), String> {
-    let left = load_file(&args.input_left);
-    let right = load_file(&args.input_right);
+    let options = MergeOptions::new(args, current_config());
+    let left = ts::read_from_file(&args.input_left)?;
+    let rights = args
+        .inputs_right
+        .iter()
+        .map(|path| ts::read_from_file(path))
+        .collect::<Result<Vec<_>, _>>()?;
 
-    if let Err(e) = left {
-        return Err(tr!(
-            "error-open-or-parse",
-            file = args.input_left.as_str(),
-            error = e.to_string()
-        ));
+    if let Some(base_path) = &args.base {
+        let [right] =
+            <[TSNode; 1]>::try_from(rights).map_err(|_| tr!("cli-merge-base-single-input"))?;
+        let base = ts::read_from_file(base_path)?;
+
+        let (result, conflicts) = merge_three_way(&base, left, right, options.strategy);
+        ts::write_to_output(&args.output_path, &result)?;
+
+        return report_conflicts(&conflicts, &args.conflict_report);
+    }
+
+    if args.update && rights.len() > 1 {
+        return Err(tr!("cli-merge-update-single-input"));
+    }
+
+    let dry_run_left = args.dry_run.then(|| left.clone());
+    let (mut result, origins) = merge_all_ts_nodes(left, rights, &options);
+
+    if let Some(left) = dry_run_left {
+        let report = MergeReport::new(&left, &result, args.no_obsolete)
+            .format(args.report_format.unwrap_or_default())?;
+
+        return match &args.output_path {
+            None => {
+                print!("{report}");
+                Ok(())
+            }
+            Some(output_path) => write_to_output(output_path, &report),
+        };
     }
 
-    if let Err(e) = right {
-        return Err(tr!(
-            "error-open-or-parse",
-            file = args.input_right.as_str(),
-            error = e.to_string()
-        ));
+    if args.no_obsolete {
+        remove_vanished(&mut result);
     }
+    ts::write_to_output(&args.output_path, &result)?;
 
-    let result = merge_ts_nodes(left.unwrap(), right.unwrap(), args.keep_translation);
+    if args.inputs_right.len() > 1 || args.provenance_report.is_some() {
+        let inputs: Vec<&String> = std::iter::once(&args.input_left)
+            .chain(&args.inputs_right)
+            .collect();
+        report_provenance(&origins, &inputs, &args.provenance_report)?;
+    }
 
-    ts::write_to_output(&args.output_path, &result)
+    Ok(())
 }
 
-/// MessageNode that can be `eq(...)`.
-#[derive(Eq, PartialOrd, Clone)]
-struct EquatableMessageNode {
-    pub node: MessageNode,
+/// Input file from which the translation of a merged message comes.
+#[derive(Debug, PartialEq)]
+struct TranslationOrigin {
+    context: String,
+    source: String,
+    /// Index of the input, 0 being the left file.
+    input: usize,
 }
 
-/// The rule for equality is if the message id or source match.
-impl PartialEq for EquatableMessageNode {
-    fn eq(&self, other: &Self) -> bool {
-        if let Some(this_id) = &self.node.id
-            && let Some(other_id) = &other.node.id
-        {
-            return this_id == other_id;
-        }
+/// Prints how many translations come from each input to stderr, and lists the origin of every
+/// translation in the report file, if any.
+fn report_provenance(
+    origins: &[TranslationOrigin],
+    inputs: &[&String],
+    report_path: &Option<String>,
+) -> Result<(), String> {
+    for (index, input) in inputs.iter().enumerate() {
+        let count = origins
+            .iter()
+            .filter(|origin| origin.input == index)
+            .count();
+        eprintln!(
+            "{}",
+            tr!(
+                "cli-merge-provenance-summary",
+                count = count,
+                file = input.as_str()
+            )
+        );
+    }
 
-        self.node.source == other.node.source
+    if let Some(report_path) = report_path {
+        let report: String = origins
+            .iter()
+            .map(|origin| {
+                format!(
+                    "{}\n",
+                    tr!(
+                        "cli-merge-provenance-line",
+                        context = origin.context.as_str(),
+                        source = origin.source.as_str(),
+                        file = inputs[origin.input].as_str()
+                    )
+                )
+            })
+            .collect();
+        write_to_output(report_path, &report)?;
     }
+
+    Ok(())
 }
 
-impl Hash for EquatableMessageNode {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.node.source.hash(state);
-        self.node.locations.iter().for_each(|loc| {
-            loc.line.hash(state);
-            loc.filename.hash(state);
-        });
+fn parse_fuzzy_threshold(value: &str) -> Result<f64, String> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|threshold| (0.0..=1.0).contains(threshold))
+        .ok_or_else(|| tr!("cli-merge-fuzzy-threshold-invalid", value = value))
+}
+
+/// Options of a two-way merge.
+#[derive(Default)]
+struct MergeOptions {
+    keep_translation: bool,
+    update: bool,
+    strategy: MatchStrategy,
+    fuzzy_threshold: Option<f64>,
+}
+
+impl MergeOptions {
+    /// Options of a merge, from the command line arguments then the project configuration.
+    fn new(args: &MergeArgs, config: &Config) -> Self {
+        MergeOptions {
+            // The right file of an update has the current sources, not their translations
+            keep_translation: args.keep_translation || args.update,
+            update: args.update,
+            strategy: args.strategy.or(config.merge.strategy).unwrap_or_default(),
+            fuzzy_threshold: args.fuzzy_threshold.or(config.merge.fuzzy_threshold),
+        }
+    }
+}
+
+/// Writes the conflicts of a three-way merge to the report file, or to stderr if there is none.
+/// Returns an error when there are conflicts, so that they are not overlooked.
+fn report_conflicts(
+    conflicts: &[MergeConflict],
+    report_path: &Option<String>,
+) -> Result<(), String> {
+    let report: String = conflicts
+        .iter()
+        .map(|conflict| {
+            format!(
+                "{}\n",
+                tr!(
+                    "cli-merge-conflict-report-line",
+                    context = conflict.context.as_str(),
+                    source = conflict.source.as_str(),
+                    left = conflict.ours.as_str(),
+                    right = conflict.theirs.as_str()
+                )
+            )
+        })
+        .collect();
+
+    match report_path {
+        Some(report_path) => write_to_output(report_path, &report)?,
+        None => eprint!("{report}"),
+    }
+
+    if conflicts.is_empty() {
+        Ok(())
+    } else {
+        Err(tr!("cli-merge-conflicts", count = conflicts.len()))
     }
 }
 
-fn merge_ts_nodes(mut left: TSNode, right: TSNode, keep_translation: bool) -> TSNode {
-    if keep_translation {
+fn merge_ts_nodes(mut left: TSNode, right: TSNode, options: &MergeOptions) -> TSNode {
+    if options.keep_translation {
         debug!(
             "--keep_translation flag is active, the following nodes will NOT be updated from the right-side file: translation, comment, oldcomment, oldsource, encoding"
         );
     }
 
-    merge_contexts(&mut left, right, keep_translation);
+    let left_lengths: Vec<usize> = left
+        .contexts
+        .iter()
+        .map(|context| context.messages.len())
+        .collect();
+    let matched = merge_contexts(&mut left, right, options);
+
+    if options.update {
+        update_vanished(&mut left, &left_lengths, &matched);
+    }
+
     left
 }
 
-fn merge_contexts(left: &mut TSNode, right: TSNode, keep_translation: bool) {
+/// Merges the right contexts into the left ones. Returns the context and message indexes of the
+/// left messages which have a matching right message.
+fn merge_contexts(
+    left: &mut TSNode,
+    right: TSNode,
+    options: &MergeOptions,
+) -> HashSet<(usize, usize)> {
+    let mut matched = HashSet::new();
+
     right.contexts.into_iter().for_each(|mut right_context| {
-        let left_context_opt = left
+        let left_context_index = left
             .contexts
-            .iter_mut()
-            .find(|left_context| left_context.name == right_context.name);
+            .iter()
+            .position(|left_context| left_context.name == right_context.name);
 
-        if let Some(left_context) = left_context_opt {
-            debug!(
-                "Found context '{}' matching in left and right files.",
-                left_context.name
-            );
-            debug!(
-                "Left context has {} messages, Right context has {} messages.",
-                left_context.messages.len(),
-                right_context.messages.len()
-            );
+        match left_context_index {
+            Some(index) => {
+                let left_context = &mut left.contexts[index];
+                debug!(
+                    "Found context '{}' matching in left and right files.",
+                    left_context.name
+                );
+                debug!(
+                    "Left context has {} messages, Right context has {} messages.",
+                    left_context.messages.len(),
+                    right_context.messages.len()
+                );
 
-            if !keep_translation {
-                left_context.comment = right_context.comment;
-                left_context.encoding = right_context.encoding;
+                if !options.keep_translation {
+                    left_context.comment = right_context.comment.take();
+                    left_context.encoding = right_context.encoding.take();
+                }
             }
-
-            left_context.messages = merge_messages(
-                &mut left_context.messages,
-                &mut right_context.messages,
-                keep_translation,
-            );
-        } else {
-            debug!(
+            None => debug!(
                 "No matching context with name '{}' in left file.",
                 right_context.name
+            ),
+        }
+
+        let had_messages = !right_context.messages.is_empty();
+        let right_messages: Vec<MessageNode> = right_context.messages.drain(..).collect();
+        let mut new_messages = match left_context_index {
+            Some(index) if options.strategy.is_left_driven() => merge_messages_left_driven(
+                &mut left.contexts[index].messages,
+                index,
+                right_messages,
+                options,
+                &mut matched,
+            ),
+            _ => {
+                let mut new_messages = vec![];
+                for right_message in right_messages {
+                    match find_message(left, left_context_index, &right_message, options.strategy) {
+                        Some((context_index, message_index)) => {
+                            merge_message(
+                                &mut left.contexts[context_index].messages[message_index],
+                                &right_message,
+                                options.keep_translation,
+                            );
+                            matched.insert((context_index, message_index));
+                        }
+                        None => new_messages.push(right_message),
+                    }
+                }
+                new_messages
+            }
+        };
+
+        if let (Some(threshold), Some(index)) = (options.fuzzy_threshold, left_context_index) {
+            new_messages = merge_fuzzy(
+                &mut left.contexts[index].messages,
+                index,
+                new_messages,
+                &mut matched,
+                threshold,
             );
-            left.contexts.push(right_context);
+        }
+
+        debug!(
+            "Expecting to add {} messages from 'right' file.",
+            new_messages.len()
+        );
+
+        match left_context_index {
+            Some(index) => left.contexts[index].messages.extend(new_messages),
+            // A context whose messages all moved to other contexts is not added
+            None if had_messages && new_messages.is_empty() => {}
+            None => {
+                right_context.messages = new_messages;
+                left.contexts.push(right_context);
+            }
         }
     });
+
+    matched
 }
 
-/// Merges two messages collections
-fn merge_messages(
-    left_messages: &mut Vec<MessageNode>,
-    right_messages: &mut Vec<MessageNode>,
-    keep_translation: bool,
+/// Updates every left message with the first matching right message, so that several left
+/// messages may take the same right one. Returns the right messages matching no left message.
+fn merge_messages_left_driven(
+    left_messages: &mut [MessageNode],
+    context_index: usize,
+    right_messages: Vec<MessageNode>,
+    options: &MergeOptions,
+    matched: &mut HashSet<(usize, usize)>,
 ) -> Vec<MessageNode> {
-    let mut unique_messages_left: Vec<_> = left_messages
-        .drain(0..)
-        .map(|node| EquatableMessageNode { node })
-        .collect();
-
-    let mut unique_messages_right: Vec<_> = right_messages
-        .drain(0..)
-        .map(|node| EquatableMessageNode { node })
-        .collect();
-
-    unique_messages_left.iter_mut().for_each(|left_message| {
-        // Find matching right message and merge information
-        let right_message = unique_messages_right
+    for (message_index, left_message) in left_messages.iter_mut().enumerate() {
+        let right_message = right_messages
             .iter()
-            .find(|&msg| msg == left_message);
+            .find(|right_message| options.strategy.matches(left_message, right_message));
 
         if let Some(right_message) = right_message {
-            debug!(
-                "Found matching message with source '{:?}' and id '{:?}' ",
-                right_message.node.source, right_message.node.id
-            );
+            merge_message(left_message, right_message, options.keep_translation);
+            matched.insert((context_index, message_index));
+        }
+    }
 
-            if right_message.node.source != left_message.node.source {
-                debug!(
-                    "Updating source '{:?}' to '{:?}'",
-                    left_message.node.source, right_message.node.source
-                );
+    right_messages
+        .into_iter()
+        .filter(|right_message| {
+            !left_messages
+                .iter()
+                .any(|left_message| options.strategy.matches(left_message, right_message))
+        })
+        .collect()
+}
 
-                left_message
-                    .node
-                    .old_source
-                    .clone_from(&left_message.node.source);
-                left_message
-                    .node
-                    .source
-                    .clone_from(&right_message.node.source);
+/// Marks the left messages missing from the right file as no longer used, as `lupdate` does:
+/// finished translations become vanished and the others obsolete. Messages which reappeared
+/// in the right file get their previous status back.
+fn update_vanished(left: &mut TSNode, left_lengths: &[usize], matched: &HashSet<(usize, usize)>) {
+    for (context_index, &length) in left_lengths.iter().enumerate() {
+        let messages = &mut left.contexts[context_index].messages[..length];
+
+        for (message_index, message) in messages.iter_mut().enumerate() {
+            if matched.contains(&(context_index, message_index)) {
+                if let Some(translation) = message.translation.as_mut() {
+                    translation.translation_type = match translation.translation_type.take() {
+                        Some(TranslationType::Vanished) => None,
+                        Some(TranslationType::Obsolete) => Some(TranslationType::Unfinished),
+                        translation_type => translation_type,
+                    };
+                }
+                continue;
             }
 
-            if right_message.node.comment != left_message.node.comment {
-                debug!(
-                    "Updating comment '{:?}' to '{:?}'",
-                    left_message.node.comment, right_message.node.comment
-                );
+            let translation = message.translation.get_or_insert_with(|| TranslationNode {
+                translation_simple: None,
+                numerus_forms: vec![],
+                translation_type: Some(TranslationType::Unfinished),
+                variants: None,
+                userdata: None,
+            });
+            let finished = matches!(
+                translation.translation_type,
+                None | Some(TranslationType::Finished) | Some(TranslationType::Vanished)
+            );
+            translation.translation_type = Some(if finished {
+                TranslationType::Vanished
+            } else {
+                TranslationType::Obsolete
+            });
+        }
+    }
+}
+
+/// Removes the vanished and obsolete messages, and the contexts left empty.
+fn remove_vanished(node: &mut TSNode) {
+    for context in node.contexts.iter_mut() {
+        context.messages.retain(|message| {
+            !matches!(
+                message
+                    .translation
+                    .as_ref()
+                    .and_then(|translation| translation.translation_type.as_ref()),
+                Some(TranslationType::Vanished) | Some(TranslationType::Obsolete)
+            )
+        });
+    }
+    node.contexts.retain(|context| !context.messages.is_empty());
+}
+
+/// Finds the left message matching the right one, first in the context of the same name, then in
+/// the other contexts if the strategy allows it. Returns the context and message indexes.
+fn find_message(
+    left: &TSNode,
+    context_index: Option<usize>,
+    right_message: &MessageNode,
+    strategy: MatchStrategy,
+) -> Option<(usize, usize)> {
+    let find_in = |index: usize| {
+        left.contexts[index]
+            .messages
+            .iter()
+            .position(|left_message| strategy.matches(left_message, right_message))
+            .map(|message_index| (index, message_index))
+    };
+
+    context_index.and_then(find_in).or_else(|| {
+        if strategy.across_contexts() {
+            (0..left.contexts.len())
+                .filter(|&index| Some(index) != context_index)
+                .find_map(find_in)
+        } else {
+            None
+        }
+    })
+}
+
+/// Merges the right files into the left one in order, each one taking precedence over the previous
+/// ones. Also returns the input from which the translation of each translated message comes.
+fn merge_all_ts_nodes(
+    mut result: TSNode,
+    rights: Vec<TSNode>,
+    options: &MergeOptions,
+) -> (TSNode, Vec<TranslationOrigin>) {
+    let translations = |node: &TSNode| -> Vec<Vec<Option<String>>> {
+        node.contexts
+            .iter()
+            .map(|context| context.messages.iter().map(translation_text).collect())
+            .collect()
+    };
+
+    let mut inputs: Vec<Vec<usize>> = translations(&result)
+        .iter()
+        .map(|messages| vec![0; messages.len()])
+        .collect();
+
+    for (index, right) in rights.into_iter().enumerate() {
+        let before = translations(&result);
+        result = merge_ts_nodes(result, right, options);
 
-                left_message
-                    .node
-                    .old_comment
-                    .clone_from(&left_message.node.comment);
-                left_message
-                    .node
-                    .comment
-                    .clone_from(&right_message.node.comment);
+        // Merging only appends contexts and messages, so the indexes of the previous ones are kept
+        for (context_index, messages) in translations(&result).iter().enumerate() {
+            if context_index == inputs.len() {
+                inputs.push(vec![]);
             }
+            let context_inputs = &mut inputs[context_index];
+            context_inputs.resize(messages.len(), index + 1);
 
-            left_message
-                .node
-                .locations
-                .clone_from(&right_message.node.locations);
+            for (message_index, translation) in messages.iter().enumerate() {
+                let previous = before
+                    .get(context_index)
+                    .and_then(|messages| messages.get(message_index));
+                if previous.is_some_and(|previous| previous != translation) {
+                    context_inputs[message_index] = index + 1;
+                }
+            }
+        }
+    }
 
-            if !keep_translation {
-                debug!(
-                    "Updating translation '{:?}' to '{:?}'",
-                    left_message.node.translation, right_message.node.translation
-                );
+    let origins = result
+        .contexts
+        .iter()
+        .zip(inputs)
+        .flat_map(|(context, context_inputs)| {
+            context
+                .messages
+                .iter()
+                .zip(context_inputs)
+                .filter(|(message, _)| translation_text(message).is_some())
+                .map(|(message, input)| TranslationOrigin {
+                    context: context.name.clone(),
+                    source: message.source.clone().unwrap_or_default(),
+                    input,
+                })
+        })
+        .collect();
+
+    (result, origins)
+}
+
+/// Matches the right messages which are new to the left context with the most similar left message
+/// having no counterpart on the right, such as a source whose typo was fixed. The matched message
+/// keeps its translation, marked unfinished for review, with its previous source in `oldsource`.
+/// Returns the right messages which are still unmatched.
+fn merge_fuzzy(
+    left_messages: &mut [MessageNode],
+    context_index: usize,
+    right_messages: Vec<MessageNode>,
+    matched: &mut HashSet<(usize, usize)>,
+    threshold: f64,
+) -> Vec<MessageNode> {
+    let mut unmatched = vec![];
+
+    for right_message in right_messages {
+        let closest = left_messages
+            .iter()
+            .enumerate()
+            .filter(|(index, left_message)| {
+                !matched.contains(&(context_index, *index))
+                    && left_message.id.is_none()
+                    && right_message.id.is_none()
+                    && left_message.comment == right_message.comment
+                    && translation_text(left_message).is_some()
+            })
+            .filter_map(|(index, left_message)| {
+                let left_source = left_message.source.as_deref()?;
+                let right_source = right_message.source.as_deref()?;
+                Some((index, similarity(left_source, right_source)))
+            })
+            .filter(|(_, similarity)| *similarity >= threshold)
+            .reduce(|closest, candidate| {
+                if candidate.1 > closest.1 {
+                    candidate
+                } else {
+                    closest
+                }
+            });
+
+        match closest {
+            Some((index, similarity)) => {
+                let left_message = &mut left_messages[index];
                 debug!(
-                    "Updating translator comment '{:?}' to '{:?}'",
-                    left_message.node.translator_comment, right_message.node.translator_comment
+                    "Fuzzy matched '{:?}' with '{:?}' (similarity {similarity:.2})",
+                    left_message.source, right_message.source
                 );
 
-                left_message.node.translation = right_message.node.translation.clone();
-                left_message.node.translator_comment =
-                    right_message.node.translator_comment.clone();
+                merge_message(left_message, &right_message, true);
+                if let Some(translation) = left_message.translation.as_mut() {
+                    translation.translation_type = Some(TranslationType::Unfinished);
+                }
+                matched.insert((context_index, index));
             }
+            None => unmatched.push(right_message),
         }
-    });
+    }
 
-    let right_message_iter: Vec<EquatableMessageNode> = unique_messages_right
-        .drain(0..)
-        .filter(|right_message| !unique_messages_left.contains(right_message))
-        .collect();
+    unmatched
+}
+
+/// Similarity of two texts from 0 (nothing in common) to 1 (equal), based on their edit distance.
+fn similarity(left: &str, right: &str) -> f64 {
+    let left: Vec<char> = left.chars().collect();
+    let right: Vec<char> = right.chars().collect();
+    let longest = left.len().max(right.len());
+    if longest == 0 {
+        return 1.0;
+    }
 
+    // Levenshtein distance, keeping a single row of the matrix
+    let mut distances: Vec<usize> = (0..=right.len()).collect();
+    for (i, left_char) in left.iter().enumerate() {
+        let mut diagonal = distances[0];
+        distances[0] = i + 1;
+        for (j, right_char) in right.iter().enumerate() {
+            let substitution = diagonal + usize::from(left_char != right_char);
+            diagonal = distances[j + 1];
+            distances[j + 1] = substitution.min(distances[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    1.0 - distances[right.len()] as f64 / longest as f64
+}
+
+/// Updates a left message with the information of the matching right message.
+fn merge_message(
+    left_message: &mut MessageNode,
+    right_message: &MessageNode,
+    keep_translation: bool,
+) {
     debug!(
-        "Expecting to add {} messages from 'right' file.",
-        right_message_iter.len()
+        "Found matching message with source '{:?}' and id '{:?}' ",
+        right_message.source, right_message.id
     );
 
-    unique_messages_left.extend(right_message_iter);
-    unique_messages_left
-        .drain(0..)
-        .map(|message| message.node)
-        .collect()
+    if right_message.source != left_message.source {
+        debug!(
+            "Updating source '{:?}' to '{:?}'",
+            left_message.source, right_message.source
+        );
+
+        left_message.old_source.clone_from(&left_message.source);
+        left_message.source.clone_from(&right_message.source);
+    }
+
+    if right_message.comment != left_message.comment {
+        debug!(
+            "Updating comment '{:?}' to '{:?}'",
+            left_message.comment, right_message.comment
+        );
+
+        left_message.old_comment.clone_from(&left_message.comment);
+        left_message.comment.clone_from(&right_message.comment);
+    }
+
+    left_message.locations.clone_from(&right_message.locations);
+
+    if !keep_translation {
+        debug!(
+            "Updating translation '{:?}' to '{:?}'",
+            left_message.translation, right_message.translation
+        );
+        debug!(
+            "Updating translator comment '{:?}' to '{:?}'",
+            left_message.translator_comment, right_message.translator_comment
+        );
+
+        left_message.translation = right_message.translation.clone();
+        left_message.translator_comment = right_message.translator_comment.clone();
+    }
+}
+
+/// Message changed differently on both sides of a three-way merge.
+#[derive(Debug, PartialEq)]
+pub struct MergeConflict {
+    pub context: String,
+    pub source: String,
+    /// Translation kept in the merged file.
+    pub ours: String,
+    /// Translation of the other side, recorded in the translator comment of the merged message.
+    pub theirs: String,
+}
+
+/// Merges the changes made to `base` on both `ours` and `theirs`, at the context and message level.
+/// A change made on one side only is taken. Locations never conflict: if both sides changed them,
+/// ours are kept, as they are regenerated by `lupdate` anyway. Messages whose translation was changed
+/// differently on both sides keep our translation, marked unfinished, with their translation recorded
+/// in the translator comment so that it can be resolved in Qt Linguist. Messages are matched with
+/// `strategy` within contexts of the same name.
+pub fn merge_three_way(
+    base: &TSNode,
+    mut ours: TSNode,
+    theirs: TSNode,
+    strategy: MatchStrategy,
+) -> (TSNode, Vec<MergeConflict>) {
+    let mut conflicts = vec![];
+    let empty_context = |name: &str| ContextNode {
+        name: name.to_owned(),
+        messages: vec![],
+        comment: None,
+        encoding: None,
+    };
+
+    let mut theirs_contexts = theirs.contexts;
+    let mut merged_contexts = vec![];
+
+    for our_context in ours.contexts.drain(..) {
+        let base_context = base
+            .contexts
+            .iter()
+            .find(|context| context.name == our_context.name);
+        let their_position = theirs_contexts
+            .iter()
+            .position(|context| context.name == our_context.name);
+
+        match (their_position, base_context) {
+            (Some(position), _) => {
+                let their_context = theirs_contexts.remove(position);
+                let base_context = base_context
+                    .cloned()
+                    .unwrap_or_else(|| empty_context(&our_context.name));
+                merged_contexts.push(merge_contexts_three_way(
+                    &base_context,
+                    our_context,
+                    their_context,
+                    strategy,
+                    &mut conflicts,
+                ));
+            }
+            // Removed on their side: keep it only if we changed it
+            (None, Some(base_context)) if same_context(base_context, &our_context) => {
+                debug!("Context '{}' was removed by theirs", our_context.name);
+            }
+            (None, _) => merged_contexts.push(our_context),
+        }
+    }
+
+    for their_context in theirs_contexts {
+        match base
+            .contexts
+            .iter()
+            .find(|context| context.name == their_context.name)
+        {
+            Some(base_context) if same_context(base_context, &their_context) => {
+                debug!("Context '{}' was removed by ours", their_context.name);
+            }
+            _ => merged_contexts.push(their_context),
+        }
+    }
+
+    ours.contexts = merged_contexts;
+    (ours, conflicts)
 }
 
-fn load_file(path: &String) -> Result<TSNode, String> {
-    match quick_xml::Reader::from_file(path) {
-        Ok(reader) => {
-            let nodes: Result<TSNode, _> = quick_xml::de::from_reader(reader.into_inner());
-            match nodes {
-                Ok(nodes) => Ok(nodes),
-                Err(err) => Err(err.to_string()),
+fn merge_contexts_three_way(
+    base: &ContextNode,
+    mut ours: ContextNode,
+    theirs: ContextNode,
+    strategy: MatchStrategy,
+    conflicts: &mut Vec<MergeConflict>,
+) -> ContextNode {
+    if ours.comment == base.comment {
+        ours.comment = theirs.comment;
+    }
+    if ours.encoding == base.encoding {
+        ours.encoding = theirs.encoding;
+    }
+
+    let mut theirs_messages = theirs.messages;
+    let mut merged_messages = vec![];
+
+    for our_message in ours.messages.drain(..) {
+        let base_message = base
+            .messages
+            .iter()
+            .find(|message| strategy.matches(message, &our_message));
+        let their_position = theirs_messages
+            .iter()
+            .position(|message| strategy.matches(message, &our_message));
+
+        match (their_position, base_message) {
+            (Some(position), _) => {
+                let their_message = theirs_messages.remove(position);
+                merged_messages.push(merge_messages_three_way(
+                    &ours.name,
+                    base_message,
+                    our_message,
+                    their_message,
+                    conflicts,
+                ));
+            }
+            // Removed on their side: keep it only if we changed it
+            (None, Some(base_message)) if same_message(base_message, &our_message) => {
+                debug!("Message {:?} was removed by theirs", our_message.source);
+            }
+            (None, _) => merged_messages.push(our_message),
+        }
+    }
+
+    for their_message in theirs_messages {
+        match base
+            .messages
+            .iter()
+            .find(|message| strategy.matches(message, &their_message))
+        {
+            Some(base_message) if same_message(base_message, &their_message) => {
+                debug!("Message {:?} was removed by ours", their_message.source);
             }
+            _ => merged_messages.push(their_message),
         }
-        Err(err) => Err(err.to_string()),
     }
+
+    ours.messages = merged_messages;
+    ours
+}
+
+/// Whether both messages are the same, locations aside: a side which only moved a message did not
+/// change it.
+fn same_message(left: &MessageNode, right: &MessageNode) -> bool {
+    let without_locations = |message: &MessageNode| MessageNode {
+        locations: vec![],
+        ..message.clone()
+    };
+
+    without_locations(left) == without_locations(right)
+}
+
+/// Whether both contexts are the same, locations of their messages aside.
+fn same_context(left: &ContextNode, right: &ContextNode) -> bool {
+    left.name == right.name
+        && left.comment == right.comment
+        && left.encoding == right.encoding
+        && left.messages.len() == right.messages.len()
+        && left
+            .messages
+            .iter()
+            .zip(&right.messages)
+            .all(|(left, right)| same_message(left, right))
+}
+
+/// Takes the value of the side that changed it, ours if both changed it.
+fn merge_value<T: PartialEq>(base: Option<&T>, ours: &mut T, theirs: T) {
+    if base.is_none_or(|base| base == ours) {
+        *ours = theirs;
+    }
+}
+
+fn merge_messages_three_way(
+    context: &str,
+    base: Option<&MessageNode>,
+    mut ours: MessageNode,
+    theirs: MessageNode,
+    conflicts: &mut Vec<MergeConflict>,
+) -> MessageNode {
+    let mut locations = ours.locations.clone();
+    merge_value(
+        base.map(|base| &base.locations),
+        &mut locations,
+        theirs.locations.clone(),
+    );
+
+    if same_message(&ours, &theirs) || base.is_some_and(|base| same_message(base, &theirs)) {
+        return MessageNode { locations, ..ours };
+    }
+    if base.is_some_and(|base| same_message(base, &ours)) {
+        return MessageNode {
+            locations,
+            ..theirs
+        };
+    }
+
+    let translation_conflict = translation_text(&ours) != translation_text(&theirs)
+        && base.is_none_or(|base| {
+            base.translation != ours.translation && base.translation != theirs.translation
+        });
+
+    if translation_conflict {
+        let ours_text = translation_text(&ours).unwrap_or_default();
+        let theirs_text = translation_text(&theirs).unwrap_or_default();
+        debug!("Conflicting translations {ours_text:?} and {theirs_text:?}");
+
+        let note = tr!(
+            "cli-merge-conflict-note",
+            translation = theirs_text.as_str()
+        );
+        ours.translator_comment = Some(match ours.translator_comment.take() {
+            Some(comment) if !comment.is_empty() => format!("{note}\n{comment}"),
+            _ => note,
+        });
+        if let Some(translation) = ours.translation.as_mut() {
+            translation.translation_type = Some(TranslationType::Unfinished);
+        }
+
+        conflicts.push(MergeConflict {
+            context: context.to_owned(),
+            source: ours.source.clone().unwrap_or_default(),
+            ours: ours_text,
+            theirs: theirs_text,
+        });
+    } else {
+        merge_value(
+            base.map(|base| &base.translation),
+            &mut ours.translation,
+            theirs.translation,
+        );
+        merge_value(
+            base.map(|base| &base.translator_comment),
+            &mut ours.translator_comment,
+            theirs.translator_comment,
+        );
+    }
+
+    ours.locations = locations;
+    merge_value(
+        base.map(|base| &base.source),
+        &mut ours.source,
+        theirs.source,
+    );
+    merge_value(
+        base.map(|base| &base.old_source),
+        &mut ours.old_source,
+        theirs.old_source,
+    );
+    merge_value(
+        base.map(|base| &base.comment),
+        &mut ours.comment,
+        theirs.comment,
+    );
+    merge_value(
+        base.map(|base| &base.old_comment),
+        &mut ours.old_comment,
+        theirs.old_comment,
+    );
+    merge_value(
+        base.map(|base| &base.extra_comment),
+        &mut ours.extra_comment,
+        theirs.extra_comment,
+    );
+
+    ours
 }
 
 #[cfg(test)]
@@ -250,30 +932,362 @@ mod merge_test {
 
     #[test]
     fn test_merge_two_files() {
-        let left = load_file(&"./test_data/example_merge_left.xml".to_string())
+        let left = ts::read_from_file("./test_data/example_merge_left.xml")
             .expect("Test data could not be loaded for left file.");
-        let right = load_file(&"./test_data/example_merge_right.xml".to_string())
+        let right = ts::read_from_file("./test_data/example_merge_right.xml")
             .expect("Test data could not be loaded for right file.");
-        let expected_result = load_file(&"./test_data/example_merge_result.xml".to_string())
+        let expected_result = ts::read_from_file("./test_data/example_merge_result.xml")
             .expect("Test data could not be loaded for right file.");
 
-        let result = merge_ts_nodes(left, right, false);
+        let result = merge_ts_nodes(left, right, &MergeOptions::default());
 
         assert_eq!(result, expected_result);
     }
 
     #[test]
     fn test_merge_two_files_keep_translations() {
-        let left = load_file(&"./test_data/example_merge_keep_translation_left.xml".to_string())
+        let left = ts::read_from_file("./test_data/example_merge_keep_translation_left.xml")
             .expect("Test data could not be loaded for left file.");
-        let right = load_file(&"./test_data/example_merge_keep_translation_right.xml".to_string())
+        let right = ts::read_from_file("./test_data/example_merge_keep_translation_right.xml")
             .expect("Test data could not be loaded for right file.");
         let expected_result =
-            load_file(&"./test_data/example_merge_keep_translation_result.xml".to_string())
+            ts::read_from_file("./test_data/example_merge_keep_translation_result.xml")
                 .expect("Test data could not be loaded for right file.");
 
-        let result = merge_ts_nodes(left, right, true);
+        let result = merge_ts_nodes(
+            left,
+            right,
+            &MergeOptions {
+                keep_translation: true,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn test_merge_strategies() {
+        let load = |name: &str| {
+            ts::read_from_file(&format!("./test_data/example_merge_strategy_{name}.xml"))
+                .expect("Test data could not be loaded.")
+        };
+        let merge = |strategy| {
+            let options = MergeOptions {
+                strategy,
+                ..Default::default()
+            };
+            merge_ts_nodes(load("left"), load("right"), &options)
+        };
+        let translations = |node: &TSNode, context: usize| -> Vec<Option<String>> {
+            node.contexts[context]
+                .messages
+                .iter()
+                .map(translation_text)
+                .collect()
+        };
+
+        assert_eq!(merge(MatchStrategy::SourceComment), load("result"));
+
+        let result = merge(MatchStrategy::SourceCommentContext);
+        assert_eq!(result.contexts.len(), 3);
+        assert_eq!(result.contexts[0], load("result").contexts[0]);
+        assert_eq!(result.contexts[2].name, "NewDialog");
+
+        // Disambiguation is ignored: both left messages take the first right one
+        let result = merge(MatchStrategy::IdOrSource);
+        assert_eq!(
+            translations(&result, 0),
+            vec![Some("Öffnen".to_owned()), Some("Öffnen".to_owned())]
+        );
+        assert_eq!(
+            result.contexts[0].messages[1].old_comment.as_deref(),
+            Some("adjective")
+        );
+
+        // Messages without id never match
+        let result = merge(MatchStrategy::Id);
+        assert_eq!(result.contexts[0].messages.len(), 4);
+        assert_eq!(result.contexts.len(), 3);
+    }
+
+    #[test]
+    fn test_merge_id_or_source_updates_every_left_message() {
+        let load = |name: &str| {
+            ts::read_from_file(&format!(
+                "./test_data/example_merge_id_or_source_{name}.xml"
+            ))
+            .expect("Test data could not be loaded.")
+        };
+
+        let result = merge_ts_nodes(load("left"), load("right"), &MergeOptions::default());
+        let messages = &result.contexts[0].messages;
+        assert_eq!(messages.len(), 2);
+        assert!(
+            messages
+                .iter()
+                .all(|message| translation_text(message).as_deref() == Some("ÖFFNEN"))
+        );
+        assert_eq!(messages[1].comment.as_deref(), Some("verb"));
+        assert_eq!(messages[1].old_comment.as_deref(), Some("adjective"));
+
+        // Only the message with the same comment is updated when disambiguation is considered
+        let options = MergeOptions {
+            strategy: MatchStrategy::SourceCommentContext,
+            ..Default::default()
+        };
+        let result = merge_ts_nodes(load("left"), load("right"), &options);
+        assert_eq!(
+            translation_text(&result.contexts[0].messages[1]).as_deref(),
+            Some("Offen")
+        );
+    }
+
+    #[test]
+    fn test_merge_fuzzy() {
+        let load = |name: &str| {
+            ts::read_from_file(&format!("./test_data/example_merge_fuzzy_{name}.xml"))
+                .expect("Test data could not be loaded.")
+        };
+        let options = MergeOptions {
+            fuzzy_threshold: Some(0.8),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            merge_ts_nodes(load("left"), load("right"), &options),
+            load("result")
+        );
+
+        let result = merge_ts_nodes(load("left"), load("right"), &MergeOptions::default());
+        assert_eq!(result.contexts[0].messages.len(), 6);
+    }
+
+    #[test]
+    fn test_merge_fuzzy_report() {
+        let load = |name: &str| {
+            ts::read_from_file(&format!("./test_data/example_merge_fuzzy_{name}.xml"))
+                .expect("Test data could not be loaded.")
+        };
+        let options = MergeOptions {
+            fuzzy_threshold: Some(0.8),
+            ..Default::default()
+        };
+
+        let result = merge_ts_nodes(load("left"), load("right"), &options);
+        let report = MergeReport::new(&load("left"), &result, false);
+
+        // Fuzzy matched messages are paired with the left message whose source they update
+        assert_eq!(
+            report.format(ReportFormat::Text).expect("Formattable"),
+            r#"Messages added (2):
+  Context "Dialog", source "Setting"
+  Context "Dialog", source "Options"
+Sources updated (1):
+  Context "Dialog", source "Receive messages": "Recieve messages" → "Receive messages"
+"#
+        );
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(similarity("Quit", "Quit"), 1.0);
+        assert_eq!(similarity("", ""), 1.0);
+        assert_eq!(similarity("abc", "xyz"), 0.0);
+        assert_eq!(similarity("Recieve", "Receive"), 5.0 / 7.0);
+        assert_eq!(similarity("Setting", "Settings"), 7.0 / 8.0);
+        assert!(parse_fuzzy_threshold("1.5").is_err());
+        assert_eq!(parse_fuzzy_threshold("0.8"), Ok(0.8));
+    }
+
+    #[test]
+    fn test_merge_many_files() {
+        const REPORT_TEST_FILE: &str = "./test_data/test_result_merge_provenance.txt";
+
+        let load = |name: &str| {
+            ts::read_from_file(&format!("./test_data/example_merge_many_{name}.xml"))
+                .expect("Test data could not be loaded.")
+        };
+        let origin = |context: &str, source: &str, input| TranslationOrigin {
+            context: context.to_owned(),
+            source: source.to_owned(),
+            input,
+        };
+
+        let (result, origins) = merge_all_ts_nodes(
+            load("1"),
+            vec![load("2"), load("3")],
+            &MergeOptions::default(),
+        );
+
+        assert_eq!(result, load("result"));
+        assert_eq!(
+            origins,
+            vec![
+                origin("Dialog", "Open", 0),
+                origin("Dialog", "Close", 1),
+                origin("Dialog", "Save", 2),
+                origin("MainWindow", "Quit", 2),
+            ]
+        );
+
+        let inputs = ["1.ts", "2.ts", "3.ts"].map(String::from);
+        let inputs: Vec<&String> = inputs.iter().collect();
+        report_provenance(&origins, &inputs, &Some(REPORT_TEST_FILE.to_owned()))
+            .expect("Report is written");
+
+        let report = std::fs::read_to_string(REPORT_TEST_FILE).expect("Report is written");
+        std::fs::remove_file(REPORT_TEST_FILE).expect("Test should clean test file.");
+
+        assert_eq!(
+            report.lines().nth(2),
+            Some("Context \"Dialog\", source \"Save\": 3.ts")
+        );
+    }
+
+    #[test]
+    fn test_merge_update() {
+        let load = |name: &str| {
+            ts::read_from_file(&format!("./test_data/example_merge_update_{name}.xml"))
+                .expect("Test data could not be loaded.")
+        };
+        let options = MergeOptions {
+            keep_translation: true,
+            update: true,
+            ..Default::default()
+        };
+
+        let mut result = merge_ts_nodes(load("left"), load("right"), &options);
+        assert_eq!(result, load("result"));
+
+        remove_vanished(&mut result);
+        assert_eq!(result.contexts.len(), 1);
+        let sources: Vec<_> = result.contexts[0]
+            .messages
+            .iter()
+            .filter_map(|message| message.source.as_deref())
+            .collect();
+        assert_eq!(sources, vec!["Open", "Save", "Help", "New"]);
+    }
+
+    #[test]
+    fn test_merge_update_report_no_obsolete() {
+        let load = |name: &str| {
+            ts::read_from_file(&format!("./test_data/example_merge_update_{name}.xml"))
+                .expect("Test data could not be loaded.")
+        };
+        let options = MergeOptions {
+            keep_translation: true,
+            update: true,
+            ..Default::default()
+        };
+
+        let result = merge_ts_nodes(load("left"), load("right"), &options);
+        let report = MergeReport::new(&load("left"), &result, true);
+
+        // Removed messages do not shift the pairs of the following messages
+        assert_eq!(
+            report.format(ReportFormat::Text).expect("Formattable"),
+            r#"Messages added (1):
+  Context "Dialog", source "New"
+Locations changed (3):
+  Context "Dialog", source "Open"
+  Context "Dialog", source "Save"
+  Context "Dialog", source "Help"
+Messages restored (2):
+  Context "Dialog", source "Save"
+  Context "Dialog", source "Help"
+Messages removed (2):
+  Context "Dialog", source "Close"
+  Context "OldDialog", source "Exit"
+"#
+        );
+    }
+
+    #[test]
+    fn test_merge_three_way() {
+        let base = ts::read_from_file("./test_data/example_merge_three_way_base.xml")
+            .expect("Test data could not be loaded for base file.");
+        let ours = ts::read_from_file("./test_data/example_merge_three_way_ours.xml")
+            .expect("Test data could not be loaded for ours file.");
+        let theirs = ts::read_from_file("./test_data/example_merge_three_way_theirs.xml")
+            .expect("Test data could not be loaded for theirs file.");
+        let expected_result = ts::read_from_file("./test_data/example_merge_three_way_result.xml")
+            .expect("Test data could not be loaded for result file.");
+
+        let (result, conflicts) = merge_three_way(&base, ours, theirs, MatchStrategy::IdOrSource);
 
         assert_eq!(result, expected_result);
+        assert_eq!(
+            conflicts,
+            vec![MergeConflict {
+                context: "Dialog".to_owned(),
+                source: "Save".to_owned(),
+                ours: "Sichern".to_owned(),
+                theirs: "Abspeichern".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_merge_three_way_ignores_moves() {
+        let base = ts::read_from_file("./test_data/example_merge_three_way_base.xml")
+            .expect("Test data could not be loaded for base file.");
+        let move_messages = |node: &mut TSNode| {
+            for message in node
+                .contexts
+                .iter_mut()
+                .flat_map(|context| &mut context.messages)
+            {
+                for location in &mut message.locations {
+                    location.line = location.line.map(|line| line + 1);
+                }
+            }
+        };
+
+        // Ours only moved the messages, theirs removed one and translated another
+        let mut ours = base.clone();
+        move_messages(&mut ours);
+        let mut theirs = base.clone();
+        theirs.contexts[0].messages.remove(3);
+        if let Some(translation) = theirs.contexts[0].messages[0].translation.as_mut() {
+            translation.translation_simple = Some("Öffnen".to_owned());
+            translation.translation_type = None;
+        }
+        theirs.contexts.remove(1);
+
+        let (result, conflicts) = merge_three_way(&base, ours, theirs, MatchStrategy::IdOrSource);
+
+        assert!(conflicts.is_empty());
+        assert_eq!(result.contexts.len(), 1);
+        let messages = &result.contexts[0].messages;
+        assert_eq!(messages.len(), 3);
+        assert_eq!(translation_text(&messages[0]).as_deref(), Some("Öffnen"));
+        assert_eq!(messages[0].locations[0].line, Some(11));
+    }
+
+    #[test]
+    fn test_three_way_conflict_report() {
+        const REPORT_TEST_FILE: &str = "./test_data/test_result_merge_conflicts.txt";
+
+        let base = ts::read_from_file("./test_data/example_merge_three_way_base.xml")
+            .expect("Test data could not be loaded for base file.");
+        let ours = ts::read_from_file("./test_data/example_merge_three_way_ours.xml")
+            .expect("Test data could not be loaded for ours file.");
+        let theirs = ts::read_from_file("./test_data/example_merge_three_way_theirs.xml")
+            .expect("Test data could not be loaded for theirs file.");
+
+        let (_, conflicts) = merge_three_way(&base, ours, theirs, MatchStrategy::IdOrSource);
+        let result = report_conflicts(&conflicts, &Some(REPORT_TEST_FILE.to_owned()));
+
+        let report = std::fs::read_to_string(REPORT_TEST_FILE).expect("Report is written");
+        std::fs::remove_file(REPORT_TEST_FILE).expect("Test should clean test file.");
+
+        assert!(result.is_err());
+        assert_eq!(
+            report,
+            "Context \"Dialog\", source \"Save\": \"Sichern\" (left) conflicts with \"Abspeichern\" (right).\n"
+        );
+        assert!(report_conflicts(&[], &None).is_ok());
     }
 }
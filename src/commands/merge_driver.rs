@@ -0,0 +1,55 @@
+use clap::{ArgAction, Args};
+use log::debug;
+
+use crate::commands::merge::merge_three_way;
+use crate::config::current_config;
+use crate::options::MatchStrategy;
+use crate::tr;
+use crate::ts;
+
+/// Merges TS files as a git merge driver, see `README.md` for its installation.
+#[derive(Args)]
+#[command(disable_help_flag = true)]
+pub struct MergeDriverArgs {
+    /// Common ancestor of both versions (`%O`).
+    #[arg(help = tr!("cli-merge-driver-base"), help_heading = tr!("cli-headers-arguments"))]
+    pub base: String,
+    /// Current version (`%A`), which receives the result of the merge.
+    #[arg(help = tr!("cli-merge-driver-ours"), help_heading = tr!("cli-headers-arguments"))]
+    pub ours: String,
+    /// Other branch's version (`%B`).
+    #[arg(help = tr!("cli-merge-driver-theirs"), help_heading = tr!("cli-headers-arguments"))]
+    pub theirs: String,
+    /// How messages of the versions are identified as being the same message.
+    #[arg(short, long, value_enum, help = tr!("cli-merge-strategy"), help_heading = tr!("cli-headers-options"))]
+    pub strategy: Option<MatchStrategy>,
+    #[arg(short, long, action = ArgAction::Help, help = tr!("cli-help"), help_heading = tr!("cli-headers-options"))]
+    pub help: Option<bool>,
+}
+
+/// Three-way merges the versions of a TS file into the current version, as git expects from
+/// a merge driver. Returns an error, making git report a conflict, if translations conflict.
+pub fn merge_driver_main(args: &MergeDriverArgs) -> Result<(), String> {
+    let base = ts::read_from_file(&args.base)?;
+    let ours = ts::read_from_file(&args.ours)?;
+    let theirs = ts::read_from_file(&args.theirs)?;
+
+    let strategy = args
+        .strategy
+        .or(current_config().merge.strategy)
+        .unwrap_or_default();
+    let (merged, conflicts) = merge_three_way(&base, ours, theirs, strategy);
+    debug!("Merge driver found {} conflicts", conflicts.len());
+
+    ts::write_to_output(&Some(args.ours.clone()), &merged)?;
+
+    if conflicts.is_empty() {
+        Ok(())
+    } else {
+        Err(tr!(
+            "cli-merge-driver-conflicts",
+            count = conflicts.len(),
+            file = args.ours.as_str()
+        ))
+    }
+}
@@ -0,0 +1,274 @@
+use serde::Serialize;
+
+use crate::commands::report::{MessageRef, to_json};
+use crate::commands::text::translation_text;
+use crate::options::ReportFormat;
+use crate::tr;
+use crate::ts::{MessageNode, TSNode};
+
+/// Changes a merge makes to the left file, listed by `merge --dry-run`.
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct MergeReport {
+    contexts_added: Vec<String>,
+    messages_added: Vec<MessageRef>,
+    sources_updated: Vec<ValueChange>,
+    comments_updated: Vec<ValueChange>,
+    translations_overwritten: Vec<ValueChange>,
+    locations_changed: Vec<MessageRef>,
+    /// Messages missing from the right file of an update, marked vanished or obsolete
+    messages_vanished: Vec<MessageRef>,
+    /// Vanished or obsolete messages which are back in the right file of an update
+    messages_restored: Vec<MessageRef>,
+    /// Messages removed by `--no-obsolete`
+    messages_removed: Vec<MessageRef>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct ValueChange {
+    context: String,
+    source: String,
+    old: Option<String>,
+    new: Option<String>,
+}
+
+impl MergeReport {
+    /// Lists the changes from the left file to the result of merging it, before `--no-obsolete`
+    /// removes the vanished messages if `remove_vanished` is set. Merging updates the left messages
+    /// in place and appends the new ones, so each left message is paired with the merged message at
+    /// the same position: this is the pairing made by the merge, fuzzy matches included.
+    pub fn new(left: &TSNode, merged: &TSNode, remove_vanished: bool) -> Self {
+        let mut report = MergeReport::default();
+
+        for (context_index, context) in merged.contexts.iter().enumerate() {
+            let left_messages: &[MessageNode] = left
+                .contexts
+                .get(context_index)
+                .map_or(&[], |left_context| &left_context.messages);
+            let is_removed = |message: &MessageNode| remove_vanished && is_vanished(message);
+
+            if context_index >= left.contexts.len() && !context.messages.iter().all(is_removed) {
+                report.contexts_added.push(context.name.clone());
+            }
+
+            for (index, message) in context.messages.iter().enumerate() {
+                let message_ref = || MessageRef::new(&context.name, message);
+                let change = |old: Option<String>, new: Option<String>| ValueChange {
+                    context: context.name.clone(),
+                    source: message.source.clone().unwrap_or_default(),
+                    old,
+                    new,
+                };
+
+                let Some(left_message) = left_messages.get(index) else {
+                    if !is_removed(message) {
+                        report.messages_added.push(message_ref());
+                    }
+                    continue;
+                };
+
+                if is_removed(message) {
+                    report
+                        .messages_removed
+                        .push(MessageRef::new(&context.name, left_message));
+                    continue;
+                }
+
+                if left_message.source != message.source {
+                    report
+                        .sources_updated
+                        .push(change(left_message.source.clone(), message.source.clone()));
+                }
+                if left_message.comment != message.comment {
+                    report.comments_updated.push(change(
+                        left_message.comment.clone(),
+                        message.comment.clone(),
+                    ));
+                }
+                let (left_translation, translation) =
+                    (translation_text(left_message), translation_text(message));
+                if left_translation != translation {
+                    report
+                        .translations_overwritten
+                        .push(change(left_translation, translation));
+                }
+                if left_message.locations != message.locations {
+                    report.locations_changed.push(message_ref());
+                }
+                match (is_vanished(left_message), is_vanished(message)) {
+                    (false, true) => report.messages_vanished.push(message_ref()),
+                    (true, false) => report.messages_restored.push(message_ref()),
+                    _ => {}
+                }
+            }
+        }
+
+        report
+    }
+
+    pub fn format(&self, format: ReportFormat) -> Result<String, String> {
+        match format {
+            ReportFormat::Text => Ok(self.to_text()),
+            ReportFormat::Json => to_json(self),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let message_line = |message: &MessageRef| {
+            tr!(
+                "cli-merge-report-message",
+                context = message.context.as_str(),
+                source = message.source.as_str()
+            )
+        };
+        let change_line = |change: &ValueChange| {
+            tr!(
+                "cli-merge-report-change",
+                context = change.context.as_str(),
+                source = change.source.as_str(),
+                old = change.old.as_deref().unwrap_or_default(),
+                new = change.new.as_deref().unwrap_or_default()
+            )
+        };
+
+        let contexts_added = self.contexts_added.len();
+        let messages_added = self.messages_added.len();
+        let sources_updated = self.sources_updated.len();
+        let comments_updated = self.comments_updated.len();
+        let translations_overwritten = self.translations_overwritten.len();
+        let locations_changed = self.locations_changed.len();
+        let messages_vanished = self.messages_vanished.len();
+        let messages_restored = self.messages_restored.len();
+        let messages_removed = self.messages_removed.len();
+        let sections = [
+            (
+                tr!("cli-merge-report-contexts-added", count = contexts_added),
+                self.contexts_added.clone(),
+            ),
+            (
+                tr!("cli-merge-report-messages-added", count = messages_added),
+                self.messages_added.iter().map(message_line).collect(),
+            ),
+            (
+                tr!("cli-merge-report-sources-updated", count = sources_updated),
+                self.sources_updated.iter().map(change_line).collect(),
+            ),
+            (
+                tr!(
+                    "cli-merge-report-comments-updated",
+                    count = comments_updated
+                ),
+                self.comments_updated.iter().map(change_line).collect(),
+            ),
+            (
+                tr!(
+                    "cli-merge-report-translations-overwritten",
+                    count = translations_overwritten
+                ),
+                self.translations_overwritten
+                    .iter()
+                    .map(change_line)
+                    .collect(),
+            ),
+            (
+                tr!(
+                    "cli-merge-report-locations-changed",
+                    count = locations_changed
+                ),
+                self.locations_changed.iter().map(message_line).collect(),
+            ),
+            (
+                tr!(
+                    "cli-merge-report-messages-vanished",
+                    count = messages_vanished
+                ),
+                self.messages_vanished.iter().map(message_line).collect(),
+            ),
+            (
+                tr!(
+                    "cli-merge-report-messages-restored",
+                    count = messages_restored
+                ),
+                self.messages_restored.iter().map(message_line).collect(),
+            ),
+            (
+                tr!(
+                    "cli-merge-report-messages-removed",
+                    count = messages_removed
+                ),
+                self.messages_removed.iter().map(message_line).collect(),
+            ),
+        ];
+
+        let text: String = sections
+            .into_iter()
+            .filter(|(_, lines)| !lines.is_empty())
+            .map(|(header, lines): (String, Vec<String>)| {
+                let lines: String = lines.iter().map(|line| format!("  {line}\n")).collect();
+                format!("{header}\n{lines}")
+            })
+            .collect();
+
+        if text.is_empty() {
+            format!("{}\n", tr!("cli-merge-report-no-changes"))
+        } else {
+            text
+        }
+    }
+}
+
+fn is_vanished(message: &MessageNode) -> bool {
+    !message.status().is_in_use()
+}
+
+#[cfg(test)]
+mod merge_report_test {
+    use super::*;
+    use crate::ts;
+
+    #[test]
+    fn test_merge_report() {
+        let left = ts::read_from_file("./test_data/example_merge_left.xml")
+            .expect("Test data could not be loaded for left file.");
+        let merged = ts::read_from_file("./test_data/example_merge_result.xml")
+            .expect("Test data could not be loaded for result file.");
+
+        let report = MergeReport::new(&left, &merged, false);
+
+        assert_eq!(
+            report.format(ReportFormat::Text).expect("Formattable"),
+            r#"Contexts added (1):
+  kernel/in_merge_from_right
+Messages added (1):
+  Context "kernel/in_merge_from_right", source "Extra Context"
+Sources updated (1):
+  Context "kernel/in_merge", source "Now Right Source": "Source string Left should be in oldsource" → "Now Right Source"
+Comments updated (1):
+  Context "kernel/in_merge", source "%1 takes at most %n argument(s). %2 is therefore invalid.": "This is a old comment from Left. Should be in oldcomment." → "Coming from right."
+Translations overwritten (2):
+  Context "kernel/in_merge", source "Now Right Source": "" → "Also, translated."
+  Context "kernel/in_merge", source "Newsletter": "Nyhetsbrev - Left" → "Nyhetsbrev - Right"
+"#
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&report.format(ReportFormat::Json).expect("Formattable"))
+                .expect("Valid JSON");
+        assert_eq!(json["contexts_added"][0], "kernel/in_merge_from_right");
+        assert_eq!(
+            json["translations_overwritten"][0]["old"],
+            serde_json::Value::Null
+        );
+        assert_eq!(
+            json["translations_overwritten"][1]["new"],
+            "Nyhetsbrev - Right"
+        );
+        assert_eq!(json["locations_changed"], serde_json::json!([]));
+
+        assert_eq!(
+            MergeReport::new(&left, &left, false)
+                .format(ReportFormat::Text)
+                .expect("Formattable"),
+            "The merge makes no changes.\n"
+        );
+    }
+}
@@ -1,12 +1,22 @@
+pub mod consistency;
+pub mod diff;
+pub mod duplicates;
 pub mod extract;
+pub mod lint;
 pub mod merge;
+pub mod merge_driver;
+pub mod merge_report;
 pub mod release;
+pub mod report;
 pub mod shell_completion;
 pub mod sort;
+pub mod spell_check;
 pub mod stat;
+pub mod status_changes;
 pub mod strip;
 
 mod hash;
+mod text;
 
 #[cfg(test)]
 mod test_utils;
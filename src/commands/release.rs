@@ -8,14 +8,15 @@ use log::debug;
 
 use crate::{
     commands::hash::ElfHasher,
-    tr,
+    config::current_config,
+    tr, ts,
     ts::{ContextNode, MessageNode, TSNode, TranslationType, YesNo},
 };
 
 #[derive(Args)]
 #[command(disable_help_flag = true)]
 pub struct ReleaseArgs {
-    /// File to release
+    /// File to release, or `@group` for a group of a single file.
     #[arg(help = tr!("cli-release-input"), help_heading = tr!("cli-headers-arguments"))]
     pub input: String,
     /// If specified, will produce output in a file at designated location instead of stdout.
@@ -26,23 +27,33 @@ pub struct ReleaseArgs {
 }
 
 pub fn release_main(args: &ReleaseArgs) -> Result<(), String> {
-    let data: TSNode = quick_xml::Reader::from_file(&args.input)
-        .map_err(|e| e.to_string())
-        .and_then(|reader| {
-            quick_xml::de::from_reader(reader.into_inner()).map_err(|e| e.to_string())
-        })
-        .map_err(|e| {
-            tr!(
-                "error-ts-file-parse",
-                file = args.input.as_str(),
-                error = e.to_string()
-            )
-        })?;
+    let input_path = current_config().expand_single_input(&args.input)?;
+    let data = ts::read_from_file(&input_path)?;
 
     let mut writer = Cursor::new(Vec::<u8>::new());
 
-    compile_to_buffer(&mut writer, &data)
-        .and_then(|_| write_output(&args.output_path, &writer.into_inner()))
+    compile_to_buffer(&mut writer, &data).and_then(|_| {
+        write_output(
+            &output_path(&input_path, &args.output_path),
+            &writer.into_inner(),
+        )
+    })
+}
+
+/// Output path from the arguments, else `<output-dir>/<file>.qm` when the project configuration
+/// has a release output directory. `None` outputs to stdout.
+fn output_path(input_path: &str, output_path: &Option<String>) -> Option<String> {
+    output_path.clone().or_else(|| {
+        let output_dir = current_config().release.output_dir.as_ref()?;
+        let file_name = std::path::Path::new(input_path).with_extension("qm");
+
+        Some(
+            std::path::Path::new(output_dir)
+                .join(file_name.file_name()?)
+                .to_string_lossy()
+                .into_owned(),
+        )
+    })
 }
 
 fn write_output(output: &Option<String>, data: &[u8]) -> Result<(), String> {
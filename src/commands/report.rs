@@ -0,0 +1,55 @@
+use std::io::Write;
+
+use log::debug;
+use serde::Serialize;
+
+use crate::tr;
+use crate::ts::MessageNode;
+
+/// Message of a report, identified by its context and source.
+#[derive(Serialize, Debug, PartialEq)]
+pub(crate) struct MessageRef {
+    pub context: String,
+    pub source: String,
+}
+
+impl MessageRef {
+    pub fn new(context: &str, message: &MessageNode) -> Self {
+        MessageRef {
+            context: context.to_owned(),
+            source: message.source.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Serializes a report as pretty printed JSON, ending with a new line.
+pub(crate) fn to_json<T: Serialize>(value: &T) -> Result<String, String> {
+    serde_json::to_string_pretty(value)
+        .map(|json| json + "\n")
+        .map_err(|e| tr!("error-json-serialize", error = e.to_string()))
+}
+
+/// Writes a report to the file at `output_path`.
+pub(crate) fn write_to_output(output_path: &String, output: &str) -> Result<(), String> {
+    debug!("Writing {} characters to '{output_path}'", output.len());
+
+    match std::fs::File::options()
+        .create(true)
+        .truncate(true)
+        .write(true)
+        .open(output_path)
+    {
+        Ok(mut file) => file.write_all(output.as_bytes()).map_err(|err| {
+            tr!(
+                "error-write-output",
+                output_path = output_path,
+                error = err.to_string()
+            )
+        }),
+        Err(e) => Err(tr!(
+            "error-write-output-open",
+            output_path = output_path,
+            error = e.to_string()
+        )),
+    }
+}
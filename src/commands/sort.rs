@@ -1,14 +1,19 @@
 use clap::{ArgAction, Args};
 
-use crate::ts::TSNode;
+use crate::config::current_config;
+use crate::options::SortOrder;
+use crate::ts::{MessageNode, TSNode};
 use crate::{tr, ts};
 
 #[derive(Args)]
 #[command(disable_help_flag = true)]
 pub struct SortArgs {
-    /// File path to sort translations from.
+    /// File path to sort translations from, or `@group` for a group of a single file.
     #[arg(help = tr!("cli-sort-input"), help_heading = tr!("cli-headers-arguments"))]
     pub input_path: String,
+    /// Order of the messages within each context.
+    #[arg(long, value_enum, help = tr!("cli-sort-order"), help_heading = tr!("cli-headers-options"))]
+    pub order: Option<SortOrder>,
     /// If specified, will produce output in a file at designated location instead of stdout.
     #[arg(short, long, help = tr!("cli-sort-output"), help_heading = tr!("cli-headers-options"))]
     pub output_path: Option<String>,
@@ -23,38 +28,33 @@ pub struct SortArgs {
 /// ## Windows notes
 /// Writing non-UTF-8 characters or non-valid UTF-8 characters to `stdout` may result in an error.
 pub fn sort_main(args: &SortArgs) -> Result<(), String> {
-    match quick_xml::Reader::from_file(&args.input_path) {
-        Ok(file) => {
-            let nodes: Result<TSNode, _> = quick_xml::de::from_reader(file.into_inner());
-            match nodes {
-                Ok(mut ts_node) => {
-                    sort_ts_node(&mut ts_node);
-                    ts::write_to_output(&args.output_path, &ts_node)
-                }
-                Err(e) => Err(tr!(
-                    "error-ts-file-parse",
-                    file = args.input_path.as_str(),
-                    error = e.to_string()
-                )),
-            }
-        }
-        Err(e) => Err(tr!(
-            "error-open-or-parse",
-            file = args.input_path.as_str(),
-            error = e.to_string()
-        )),
-    }
+    let config = current_config();
+    let input_path = config.expand_single_input(&args.input_path)?;
+
+    let mut ts_node = ts::read_from_file(&input_path)?;
+    sort_ts_node(
+        &mut ts_node,
+        args.order.or(config.sort.order).unwrap_or_default(),
+    );
+    ts::write_to_output(&args.output_path, &ts_node)
 }
 
 /// Sorts the TS document with the following rules:
 /// 1. Context comes before no-context messages.
 /// 2. Context are ordered by name.
-/// 3. Messages are ordered by filename then by line.
-fn sort_ts_node(ts_node: &mut TSNode) {
+/// 3. Messages are ordered by filename then by line, or by source then by comment.
+fn sort_ts_node(ts_node: &mut TSNode, order: SortOrder) {
     let contexts = &mut ts_node.contexts;
     contexts.sort();
     contexts.iter_mut().for_each(|context| {
-        context.messages.sort();
+        match order {
+            SortOrder::Location => context.messages.sort(),
+            SortOrder::Source => context.messages.sort_by(|left, right| {
+                source_key(left)
+                    .cmp(&source_key(right))
+                    .then_with(|| left.cmp(right))
+            }),
+        }
         context
             .messages
             .iter_mut()
@@ -62,6 +62,14 @@ fn sort_ts_node(ts_node: &mut TSNode) {
     });
 }
 
+/// Key of a message when sorting by source.
+fn source_key(message: &MessageNode) -> (&str, &str) {
+    (
+        message.source.as_deref().unwrap_or_default(),
+        message.comment.as_deref().unwrap_or_default(),
+    )
+}
+
 #[cfg(test)]
 mod sort_test {
     use crate::commands::test_utils::{node_to_formatted_string, read_test_file};
@@ -78,10 +86,33 @@ mod sort_test {
             quick_xml::de::from_reader(reader_nosort.into_inner()).expect("Parsable")
         };
 
-        sort_ts_node(&mut data_nosort);
+        sort_ts_node(&mut data_nosort, SortOrder::Location);
 
         let sorted = node_to_formatted_string(&data_nosort);
 
         assert_eq!(expected_sorted, sorted);
     }
+
+    #[test]
+    fn test_sort_ts_node_by_source() {
+        let mut ts_node = ts::read_from_file("./test_data/example_sort.xml").expect("Parsable");
+
+        sort_ts_node(&mut ts_node, SortOrder::Source);
+
+        for context in &ts_node.contexts {
+            assert!(
+                context
+                    .messages
+                    .is_sorted_by(|left, right| source_key(left) <= source_key(right))
+            );
+        }
+        assert_eq!(
+            ts_node.contexts[1]
+                .messages
+                .iter()
+                .map(|message| message.source.as_deref().unwrap_or_default())
+                .collect::<Vec<_>>(),
+            vec!["Name", "Practice more", "This is just a Sample"]
+        );
+    }
 }
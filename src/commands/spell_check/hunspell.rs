@@ -0,0 +1,404 @@
+use std::collections::HashMap;
+
+use log::debug;
+
+// This is a minimal reader for Hunspell dictionaries (`.aff` and `.dic` files). It supports:
+// * Prefixes and suffixes (`PFX`, `SFX`) with their conditions and cross products;
+// * Flag formats (`FLAG long`, `FLAG num`, `FLAG UTF-8`) and flag aliases (`AF`);
+// * Simple compounding (`COMPOUNDFLAG`, `COMPOUNDMIN`);
+// * `FORBIDDENWORD` and `NEEDAFFIX`;
+// * `UTF-8` and `ISO8859-1` encodings.
+// Other Hunspell features (two-level affixes, compound rules, suggestions...) are not supported.
+// See https://man.archlinux.org/man/hunspell.5 for the format.
+
+type Flag = u32;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+enum FlagFormat {
+    /// One character per flag
+    #[default]
+    Single,
+    /// Two characters per flag
+    Long,
+    /// Comma separated numbers
+    Numeric,
+}
+
+/// Part of an affix condition, matching a single character.
+#[derive(Debug, PartialEq)]
+enum ConditionPart {
+    Any,
+    OneOf(Vec<char>),
+    NoneOf(Vec<char>),
+}
+
+impl ConditionPart {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            ConditionPart::Any => true,
+            ConditionPart::OneOf(chars) => chars.contains(&c),
+            ConditionPart::NoneOf(chars) => !chars.contains(&c),
+        }
+    }
+}
+
+fn parse_condition(condition: &str) -> Vec<ConditionPart> {
+    let mut parts = vec![];
+    let mut chars = condition.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '.' => parts.push(ConditionPart::Any),
+            '[' => {
+                let class: String = chars.by_ref().take_while(|c| *c != ']').collect();
+                match class.strip_prefix('^') {
+                    Some(negated) => parts.push(ConditionPart::NoneOf(negated.chars().collect())),
+                    None => parts.push(ConditionPart::OneOf(class.chars().collect())),
+                }
+            }
+            c => parts.push(ConditionPart::OneOf(vec![c])),
+        }
+    }
+
+    parts
+}
+
+#[derive(Debug)]
+struct Affix {
+    flag: Flag,
+    cross_product: bool,
+    strip: String,
+    add: String,
+    condition: Vec<ConditionPart>,
+}
+
+impl Affix {
+    /// Root of `word` if this affix is a suffix of it and the root ends as the condition requires.
+    fn strip_suffix(&self, word: &str) -> Option<String> {
+        let root = format!("{}{}", word.strip_suffix(self.add.as_str())?, self.strip);
+        let root_chars: Vec<char> = root.chars().collect();
+
+        (!root.is_empty()
+            && root_chars.len() >= self.condition.len()
+            && root_chars[root_chars.len() - self.condition.len()..]
+                .iter()
+                .zip(&self.condition)
+                .all(|(c, part)| part.matches(*c)))
+        .then_some(root)
+    }
+
+    /// Root of `word` if this affix is a prefix of it and the root starts as the condition requires.
+    fn strip_prefix(&self, word: &str) -> Option<String> {
+        let root = format!("{}{}", self.strip, word.strip_prefix(self.add.as_str())?);
+
+        (!root.is_empty()
+            && root.chars().count() >= self.condition.len()
+            && root
+                .chars()
+                .zip(&self.condition)
+                .all(|(c, part)| part.matches(c)))
+        .then_some(root)
+    }
+}
+
+/// Dictionary loaded from Hunspell `.aff` and `.dic` files.
+#[derive(Debug, Default)]
+pub struct Dictionary {
+    /// Stems and the flags of each of their homonyms
+    words: HashMap<String, Vec<Vec<Flag>>>,
+    prefixes: Vec<Affix>,
+    suffixes: Vec<Affix>,
+    flag_format: FlagFormat,
+    flag_aliases: Vec<Vec<Flag>>,
+    compound_flag: Option<Flag>,
+    compound_min: usize,
+    forbidden_flag: Option<Flag>,
+    need_affix_flag: Option<Flag>,
+}
+
+/// Decodes a dictionary file, which is either UTF-8 or ISO8859-1 (Latin-1).
+pub fn decode(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_owned(),
+        Err(_) => bytes.iter().map(|byte| *byte as char).collect(),
+    }
+}
+
+impl Dictionary {
+    /// Parses the content of the affix (`.aff`) then dictionary (`.dic`) files.
+    pub fn parse(aff: &str, dic: &str) -> Dictionary {
+        let mut dictionary = Dictionary {
+            compound_min: 3,
+            ..Dictionary::default()
+        };
+
+        dictionary.parse_aff(aff);
+        dictionary.parse_dic(dic);
+
+        debug!(
+            "Loaded Hunspell dictionary: {} stems, {} prefixes, {} suffixes",
+            dictionary.words.len(),
+            dictionary.prefixes.len(),
+            dictionary.suffixes.len()
+        );
+
+        dictionary
+    }
+
+    fn parse_flags(&self, flags: &str) -> Vec<Flag> {
+        match self.flag_format {
+            FlagFormat::Single => flags.chars().map(|c| c as Flag).collect(),
+            FlagFormat::Long => flags
+                .chars()
+                .collect::<Vec<char>>()
+                .chunks(2)
+                .map(|pair| pair.iter().fold(0, |flag, c| (flag << 16) | *c as Flag))
+                .collect(),
+            FlagFormat::Numeric => flags
+                .split(',')
+                .filter_map(|flag| flag.trim().parse().ok())
+                .collect(),
+        }
+    }
+
+    fn parse_single_flag(&self, flag: &str) -> Option<Flag> {
+        self.parse_flags(flag).first().copied()
+    }
+
+    /// Resolves the flags of a dictionary word, which may be a 1-based index in the flag aliases.
+    fn resolve_flags(&self, flags: &str) -> Vec<Flag> {
+        if !self.flag_aliases.is_empty()
+            && let Ok(index) = flags.parse::<usize>()
+        {
+            return self
+                .flag_aliases
+                .get(index.wrapping_sub(1))
+                .cloned()
+                .unwrap_or_default();
+        }
+
+        self.parse_flags(flags)
+    }
+
+    fn parse_aff(&mut self, aff: &str) {
+        let mut cross_products = HashMap::<(bool, Flag), bool>::new();
+        let mut aliases_header_seen = false;
+
+        for line in aff.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+
+            match fields.as_slice() {
+                ["FLAG", "long", ..] => self.flag_format = FlagFormat::Long,
+                ["FLAG", "num", ..] => self.flag_format = FlagFormat::Numeric,
+                // First `AF` line is the number of aliases
+                ["AF", _, ..] if !aliases_header_seen => aliases_header_seen = true,
+                ["AF", flags, ..] => {
+                    let flags = self.parse_flags(flags);
+                    self.flag_aliases.push(flags);
+                }
+                ["COMPOUNDFLAG", flag, ..] => self.compound_flag = self.parse_single_flag(flag),
+                ["COMPOUNDMIN", min, ..] => self.compound_min = min.parse().unwrap_or(3).max(1),
+                ["FORBIDDENWORD", flag, ..] => self.forbidden_flag = self.parse_single_flag(flag),
+                ["NEEDAFFIX", flag, ..] => self.need_affix_flag = self.parse_single_flag(flag),
+                [
+                    kind @ ("PFX" | "SFX"),
+                    flag,
+                    cross_product @ ("Y" | "N"),
+                    count,
+                ] if count.parse::<usize>().is_ok() => {
+                    if let Some(flag) = self.parse_single_flag(flag) {
+                        cross_products.insert((*kind == "PFX", flag), *cross_product == "Y");
+                    }
+                }
+                [kind @ ("PFX" | "SFX"), flag, strip, add, rest @ ..] => {
+                    let Some(flag) = self.parse_single_flag(flag) else {
+                        continue;
+                    };
+
+                    let is_prefix = *kind == "PFX";
+                    // Continuation classes (`add/flags`) are not supported, only the affix is kept
+                    let add = add.split('/').next().unwrap_or_default();
+
+                    let affix = Affix {
+                        flag,
+                        cross_product: cross_products
+                            .get(&(is_prefix, flag))
+                            .copied()
+                            .unwrap_or_default(),
+                        strip: if *strip == "0" {
+                            String::new()
+                        } else {
+                            strip.to_string()
+                        },
+                        add: if add == "0" {
+                            String::new()
+                        } else {
+                            add.to_string()
+                        },
+                        condition: parse_condition(rest.first().copied().unwrap_or(".")),
+                    };
+
+                    if is_prefix {
+                        self.prefixes.push(affix);
+                    } else {
+                        self.suffixes.push(affix);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_dic(&mut self, dic: &str) {
+        // First line is the approximate number of words, morphological fields follow a whitespace
+        for line in dic.lines().skip(1) {
+            let entry = line.split(['\t', ' ']).next().unwrap_or_default();
+
+            if entry.is_empty() {
+                continue;
+            }
+
+            let (word, flags) = match entry.split_once('/') {
+                Some((word, flags)) => (word, self.resolve_flags(flags)),
+                None => (entry, vec![]),
+            };
+
+            self.words.entry(word.to_owned()).or_default().push(flags);
+        }
+    }
+
+    /// Whether `stem` is in the dictionary with the required flags, and is not forbidden.
+    fn has_stem(&self, stem: &str, required: &[Flag], affixed: bool) -> bool {
+        self.words.get(stem).is_some_and(|homonyms| {
+            homonyms.iter().any(|flags| {
+                required.iter().all(|flag| flags.contains(flag))
+                    && !self
+                        .forbidden_flag
+                        .is_some_and(|flag| flags.contains(&flag))
+                    && (affixed
+                        || !self
+                            .need_affix_flag
+                            .is_some_and(|flag| flags.contains(&flag)))
+            })
+        })
+    }
+
+    /// Whether `word` is a stem, possibly with a prefix and/or a suffix, having all `required` flags.
+    fn check_affixed(&self, word: &str, required: &[Flag]) -> bool {
+        if self.has_stem(word, required, false) {
+            return true;
+        }
+
+        let by_suffix = |word: &str, prefix_flag: Option<Flag>| {
+            self.suffixes.iter().any(|suffix| {
+                (prefix_flag.is_none() || suffix.cross_product)
+                    && suffix.strip_suffix(word).is_some_and(|root| {
+                        let flags: Vec<Flag> = required
+                            .iter()
+                            .copied()
+                            .chain(Some(suffix.flag))
+                            .chain(prefix_flag)
+                            .collect();
+                        self.has_stem(&root, &flags, true)
+                    })
+            })
+        };
+
+        by_suffix(word, None)
+            || self.prefixes.iter().any(|prefix| {
+                prefix.strip_prefix(word).is_some_and(|root| {
+                    let flags: Vec<Flag> =
+                        required.iter().copied().chain(Some(prefix.flag)).collect();
+
+                    self.has_stem(&root, &flags, true)
+                        || (prefix.cross_product && by_suffix(&root, Some(prefix.flag)))
+                })
+            })
+    }
+
+    /// Whether `word` is made of several words allowed in compounds.
+    fn check_compound(&self, word: &str) -> bool {
+        let Some(compound_flag) = self.compound_flag else {
+            return false;
+        };
+
+        let boundaries: Vec<usize> = word.char_indices().map(|(index, _)| index).collect();
+
+        (self.compound_min..=boundaries.len().saturating_sub(self.compound_min)).any(|split| {
+            let (head, tail) = word.split_at(boundaries[split]);
+            self.has_stem(head, &[compound_flag], false)
+                && (self.check_affixed(tail, &[compound_flag]) || self.check_compound(tail))
+        })
+    }
+
+    fn check_exact(&self, word: &str) -> bool {
+        self.check_affixed(word, &[]) || self.check_compound(word)
+    }
+
+    /// Whether `word` is correctly spelled. Capitalized and uppercase words are also
+    /// accepted when their lowercase (or capitalized) form is in the dictionary.
+    pub fn check(&self, word: &str) -> bool {
+        if self.check_exact(word) {
+            return true;
+        }
+
+        let mut chars = word.chars();
+        let Some(first) = chars.next() else {
+            return true;
+        };
+
+        let lowercase = word.to_lowercase();
+
+        if word.chars().all(|c| !c.is_lowercase()) {
+            let capitalized: String = first
+                .to_uppercase()
+                .chain(chars.as_str().to_lowercase().chars())
+                .collect();
+            self.check_exact(&lowercase) || self.check_exact(&capitalized)
+        } else if first.is_uppercase() {
+            self.check_exact(&lowercase)
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod hunspell_test {
+    use super::*;
+
+    #[test]
+    fn test_check_words() {
+        let aff = std::fs::read("./test_data/spelling/en_US.aff").expect("Affix file exists");
+        let dic = std::fs::read("./test_data/spelling/en_US.dic").expect("Dictionary file exists");
+        let dictionary = Dictionary::parse(&decode(&aff), &decode(&dic));
+
+        assert!(dictionary.check("open"));
+        assert!(dictionary.check("opens"));
+        assert!(dictionary.check("reopen"));
+        assert!(dictionary.check("reopens"));
+        assert!(dictionary.check("libraries"));
+        assert!(dictionary.check("Libraries"));
+        assert!(dictionary.check("LIBRARIES"));
+        assert!(dictionary.check("workspace"));
+        assert!(dictionary.check("workspaces"));
+        assert!(dictionary.check("Qt"));
+        assert!(dictionary.check("QT"));
+
+        assert!(!dictionary.check("librarys"));
+        assert!(!dictionary.check("qt"));
+        assert!(!dictionary.check("colour"));
+        assert!(!dictionary.check("opened"));
+        assert!(!dictionary.check("wrok"));
+    }
+
+    #[test]
+    fn test_long_flags_and_aliases() {
+        let aff = "FLAG long\nAF 1\nAF AaBb\nSFX Aa Y 1\nSFX Aa 0 s .\n";
+        let dic = "1\ncat/1\n";
+        let dictionary = Dictionary::parse(aff, dic);
+
+        assert!(dictionary.check("cats"));
+        assert!(!dictionary.check("catz"));
+    }
+}
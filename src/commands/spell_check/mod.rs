@@ -0,0 +1,273 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use clap::{ArgAction, Args};
+use log::debug;
+
+use crate::commands::lint::output::DiagnosticsReport;
+use crate::commands::lint::{Diagnostic, linted_messages, linted_texts, load_file};
+use crate::commands::report::write_to_output;
+use crate::commands::spell_check::hunspell::Dictionary;
+use crate::commands::text::extract_words;
+use crate::config::current_config;
+use crate::options::OutputFormat;
+use crate::tr;
+use crate::ts::TSNode;
+
+mod hunspell;
+
+const RULE: &str = "spelling";
+
+/// Checks the spelling of translations using local Hunspell dictionaries.
+#[derive(Args)]
+#[command(disable_help_flag = true)]
+pub struct SpellCheckArgs {
+    /// File path to check translations from, or `@group` for a group of the configuration.
+    #[arg(help = tr!("cli-spell-check-input"), help_heading = tr!("cli-headers-arguments"))]
+    pub input_path: String,
+    /// Directories containing the Hunspell dictionaries, e.g. `/usr/share/hunspell`.
+    #[arg(short, long, num_args = 1.., help = tr!("cli-spell-check-dictionary-dir"), help_heading = tr!("cli-headers-options"))]
+    pub dictionary_dir: Vec<String>,
+    /// Language of the dictionary to use, instead of the language of the file.
+    #[arg(short, long, help = tr!("cli-spell-check-language"), help_heading = tr!("cli-headers-options"))]
+    pub language: Option<String>,
+    /// Files of project words (product names, jargon...) accepted in addition to the dictionary.
+    #[arg(short, long, num_args = 1.., help = tr!("cli-spell-check-word-list"), help_heading = tr!("cli-headers-options"))]
+    pub word_list: Vec<String>,
+    /// Format in which misspellings are reported.
+    #[arg(short, long, value_enum, help = tr!("cli-spell-check-format"), help_heading = tr!("cli-headers-options"))]
+    pub format: Option<OutputFormat>,
+    /// If specified, will produce output in a file at designated location instead of stdout.
+    #[arg(short, long, help = tr!("cli-spell-check-output"), help_heading = tr!("cli-headers-options"))]
+    pub output_path: Option<String>,
+    #[arg(short, long, action = ArgAction::Help, help = tr!("cli-help"), help_heading = tr!("cli-headers-options"))]
+    pub help: Option<bool>,
+}
+
+/// Hunspell dictionary along with the project words.
+struct SpellChecker {
+    dictionary: Dictionary,
+    /// Project words, in lowercase
+    words: HashSet<String>,
+}
+
+impl SpellChecker {
+    fn is_correct(&self, word: &str) -> bool {
+        // Words with digits are usually identifiers or units (e.g. `mp3`, `10px`)
+        word.chars().count() < 2
+            || word.chars().any(|c| c.is_numeric())
+            || self.words.contains(&word.to_lowercase())
+            || self.dictionary.check(word)
+    }
+
+    fn load_word_list(&mut self, path: &str) -> Result<(), String> {
+        let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+
+        self.words.extend(
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_lowercase),
+        );
+
+        Ok(())
+    }
+}
+
+/// Finds `<language>.aff` and `<language>.dic` in the first directory having them.
+/// `de-DE` is searched as `de_DE`, then as `de`.
+fn find_dictionary(directories: &[String], language: &str) -> Option<(PathBuf, PathBuf)> {
+    let language = language.replace('-', "_");
+    let mut names = vec![language.clone()];
+    if let Some((prefix, _)) = language.split_once('_') {
+        names.push(prefix.to_owned());
+    }
+
+    directories.iter().find_map(|directory| {
+        names.iter().find_map(|name| {
+            let aff = Path::new(directory).join(format!("{name}.aff"));
+            let dic = Path::new(directory).join(format!("{name}.dic"));
+            (aff.is_file() && dic.is_file()).then_some((aff, dic))
+        })
+    })
+}
+
+fn load_dictionary(aff_path: &Path, dic_path: &Path) -> Result<Dictionary, String> {
+    let read = |path: &Path| {
+        std::fs::read(path).map_err(|e| {
+            tr!(
+                "error-open-or-parse",
+                file = path.display().to_string(),
+                error = e.to_string()
+            )
+        })
+    };
+
+    let aff = hunspell::decode(&read(aff_path)?);
+    let dic = hunspell::decode(&read(dic_path)?);
+
+    Ok(Dictionary::parse(&aff, &dic))
+}
+
+/// Checks the spelling of the translations of the input file, reporting misspelled words per message.
+/// Returns an error when misspellings are found, so that it can be used in CI.
+pub fn spell_check_main(args: &SpellCheckArgs) -> Result<(), String> {
+    let config = current_config();
+    let mut report = DiagnosticsReport::default();
+
+    let dictionary_dirs = match args.dictionary_dir.is_empty() {
+        true => &config.spell_check.dictionary_dir,
+        false => &args.dictionary_dir,
+    };
+    let word_lists = match args.word_list.is_empty() {
+        true => &config.spell_check.word_list,
+        false => &args.word_list,
+    };
+
+    for input_path in config.expand_input(&args.input_path)? {
+        let (content, ts_node) = load_file(&input_path)?;
+
+        let language = args
+            .language
+            .as_deref()
+            .or(ts_node.language.as_deref())
+            .filter(|language| !language.is_empty())
+            .ok_or_else(|| tr!("cli-spell-check-no-language", file = input_path.as_str()))?;
+
+        let (aff_path, dic_path) = find_dictionary(dictionary_dirs, language)
+            .ok_or_else(|| tr!("cli-spell-check-no-dictionary", language = language))?;
+        debug!("Using dictionary {}", dic_path.display());
+
+        let mut checker = SpellChecker {
+            dictionary: load_dictionary(&aff_path, &dic_path)?,
+            words: HashSet::new(),
+        };
+
+        for path in word_lists {
+            checker.load_word_list(path).map_err(|e| {
+                tr!(
+                    "error-open-or-parse",
+                    file = path.as_str(),
+                    error = e.to_string()
+                )
+            })?;
+        }
+
+        report.add_file(
+            &input_path,
+            &content,
+            spell_check_ts_node(&ts_node, &checker),
+        );
+    }
+
+    let output = report.format(
+        args.format
+            .or(config.spell_check.format)
+            .unwrap_or_default(),
+    )?;
+
+    match &args.output_path {
+        None => print!("{output}"),
+        Some(output_path) => write_to_output(output_path, &output)?,
+    }
+
+    let count = report.diagnostics().count();
+
+    if count == 0 {
+        Ok(())
+    } else {
+        Err(tr!(
+            "cli-spell-check-problems-found",
+            count = count,
+            file = args.input_path.as_str()
+        ))
+    }
+}
+
+fn spell_check_ts_node(ts_node: &TSNode, checker: &SpellChecker) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+
+    for (context_index, context) in ts_node.contexts.iter().enumerate() {
+        for linted_message in linted_messages(context_index, context) {
+            for linted in linted_texts(linted_message.message) {
+                let mut misspelled: Vec<String> = vec![];
+
+                for word in extract_words(linted.text) {
+                    if !checker.is_correct(&word) && !misspelled.contains(&word) {
+                        misspelled.push(word);
+                    }
+                }
+
+                if !misspelled.is_empty() {
+                    diagnostics.extend(Diagnostic::for_message(
+                        RULE,
+                        linted_message,
+                        tr!(
+                            "cli-spell-check-misspelled",
+                            target = linted.target(),
+                            words = misspelled.join(", ")
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    debug!("Spell check found {} problems", diagnostics.len());
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod spell_check_test {
+    use super::*;
+
+    #[test]
+    fn test_find_dictionary() {
+        let directories = vec!["./test_data".to_owned(), "./test_data/spelling".to_owned()];
+
+        assert!(find_dictionary(&directories, "en-US").is_some());
+        assert!(find_dictionary(&directories, "fi").is_none());
+    }
+
+    #[test]
+    fn test_spell_check() {
+        let reader = quick_xml::Reader::from_file("./test_data/spelling/example_spell_check.xml")
+            .expect("Couldn't open spell check test file");
+        let data: TSNode = quick_xml::de::from_reader(reader.into_inner()).expect("Parsable");
+
+        let (aff_path, dic_path) =
+            find_dictionary(&["./test_data/spelling".to_owned()], "en_US").expect("Dictionary");
+        let mut checker = SpellChecker {
+            dictionary: load_dictionary(&aff_path, &dic_path).expect("Loadable"),
+            words: HashSet::new(),
+        };
+
+        let messages = |checker: &SpellChecker| -> Vec<String> {
+            spell_check_ts_node(&data, checker)
+                .into_iter()
+                .map(|diagnostic| diagnostic.message)
+                .collect()
+        };
+
+        assert_eq!(
+            messages(&checker),
+            vec![
+                "Misspelled words in the translation: flie, Qtcreator.",
+                "Misspelled words in numerus form 2: librarys.",
+            ]
+        );
+
+        checker
+            .load_word_list("./test_data/spelling/words.txt")
+            .expect("Word list is loadable");
+        assert_eq!(
+            messages(&checker),
+            vec![
+                "Misspelled words in the translation: flie.",
+                "Misspelled words in numerus form 2: librarys.",
+            ]
+        );
+    }
+}
@@ -0,0 +1,391 @@
+use std::collections::BTreeMap;
+use std::ops::AddAssign;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::commands::report::to_json;
+use crate::commands::stat::output::{StatFormat, escape_csv};
+use crate::commands::stat::{TotalStats, percent_complete};
+use crate::tr;
+
+/// Completion of translation files: a language × module matrix, with an overall column for each
+/// language and an overall row for each module.
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub(super) struct Dashboard {
+    pub modules: Vec<String>,
+    pub languages: Vec<DashboardRow>,
+    /// Completion of each module over all languages
+    pub overall: DashboardRow,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub(super) struct DashboardRow {
+    /// Language of the files, none for the overall row
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    /// Completion by module. Modules without a file in this language are absent.
+    pub modules: BTreeMap<String, Completion>,
+    /// Completion over all modules
+    pub overall: Completion,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub(super) struct Completion {
+    pub finished: usize,
+    /// Messages which are neither obsolete nor vanished
+    pub in_use: usize,
+    pub percent_complete: f64,
+}
+
+impl Completion {
+    fn new(finished: usize, in_use: usize) -> Self {
+        Completion {
+            finished,
+            in_use,
+            percent_complete: percent_complete(finished, in_use),
+        }
+    }
+}
+
+impl Completion {
+    /// Completion of a whole file.
+    pub fn of(stats: &TotalStats) -> Self {
+        let mut completion = Completion::default();
+        for context in &stats.contexts {
+            completion += Completion::new(
+                context.finished,
+                context.finished + context.unfinished + context.missing,
+            );
+        }
+        completion
+    }
+}
+
+impl AddAssign<Completion> for Completion {
+    fn add_assign(&mut self, other: Completion) {
+        *self = Completion::new(self.finished + other.finished, self.in_use + other.in_use);
+    }
+}
+
+impl DashboardRow {
+    fn add(&mut self, module: &str, completion: Completion) {
+        *self.modules.entry(module.to_owned()).or_default() += completion;
+        self.overall += completion;
+    }
+}
+
+/// Module of a translation file: its name without the language suffix, e.g. `app` for
+/// `translations/app_de.ts`.
+pub(super) fn module_name(path: &str, language: &str) -> String {
+    let stem = Path::new(path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    [language.to_owned(), language.to_lowercase()]
+        .iter()
+        .filter(|language| !language.is_empty())
+        .flat_map(|language| ['_', '-', '.'].map(|separator| format!("{separator}{language}")))
+        .find_map(|suffix| stem.strip_suffix(&suffix).map(str::to_owned))
+        .filter(|module| !module.is_empty())
+        .unwrap_or(stem)
+}
+
+impl Dashboard {
+    /// Builds the dashboard from the statistics of each file, along with its path and language.
+    pub fn new<'a>(files: impl IntoIterator<Item = (&'a str, &'a str, &'a TotalStats)>) -> Self {
+        let mut rows = BTreeMap::<&str, DashboardRow>::new();
+        let mut dashboard = Dashboard::default();
+
+        for (path, language, stats) in files {
+            let module = module_name(path, language);
+            let completion = Completion::of(stats);
+            rows.entry(language)
+                .or_insert_with(|| DashboardRow {
+                    language: Some(language.to_owned()),
+                    ..DashboardRow::default()
+                })
+                .add(&module, completion);
+            dashboard.overall.add(&module, completion);
+        }
+
+        dashboard.modules = dashboard.overall.modules.keys().cloned().collect();
+        dashboard.languages = rows.into_values().collect();
+        dashboard
+    }
+
+    pub fn format(&self, format: StatFormat) -> Result<String, String> {
+        match format {
+            StatFormat::Text => Ok(self.to_text()),
+            StatFormat::Json => to_json(self),
+            StatFormat::Csv => Ok(self.to_csv()),
+            StatFormat::Markdown => Ok(self.to_markdown()),
+            StatFormat::Html => Ok(self.to_html()),
+        }
+    }
+
+    /// Rows of the matrix, the overall row last, with their label.
+    fn rows(&self) -> impl Iterator<Item = (String, &DashboardRow)> {
+        self.languages
+            .iter()
+            .map(|row| (language_label(row), row))
+            .chain([(tr!("cli-stat-overall"), &self.overall)])
+    }
+
+    fn to_text(&self) -> String {
+        let overall = tr!("cli-stat-overall");
+        let headers: Vec<&str> = self
+            .modules
+            .iter()
+            .map(String::as_str)
+            .chain([overall.as_str()])
+            .collect();
+        let label_width = self
+            .rows()
+            .map(|(label, _)| label.chars().count())
+            .chain([tr!("cli-stat-language").chars().count()])
+            .max()
+            .unwrap_or_default();
+
+        let mut text = format!("{: <label_width$}", tr!("cli-stat-language"));
+        for header in &headers {
+            text.push_str(&format!("  {header: >8}"));
+        }
+        text.push('\n');
+
+        for (label, row) in self.rows() {
+            text.push_str(&format!("{label: <label_width$}"));
+            for (header, module) in headers.iter().zip(
+                self.modules
+                    .iter()
+                    .map(|module| row.modules.get(module))
+                    .chain([Some(&row.overall)]),
+            ) {
+                let width = header.chars().count().max(8);
+                let cell = module
+                    .map(|completion| format!("{:.1}%", completion.percent_complete))
+                    .unwrap_or_else(|| "-".to_owned());
+                text.push_str(&format!("  {cell: >width$}"));
+            }
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// One row by language and module, the overall completions being left to the charting tool.
+    fn to_csv(&self) -> String {
+        let mut csv = String::from("language,module,finished,in_use,percent_complete\n");
+
+        for row in &self.languages {
+            let language = escape_csv(row.language.as_deref().unwrap_or_default());
+            for (module, completion) in &row.modules {
+                csv.push_str(&format!(
+                    "{language},{},{},{},{}\n",
+                    escape_csv(module),
+                    completion.finished,
+                    completion.in_use,
+                    completion.percent_complete
+                ));
+            }
+        }
+
+        csv
+    }
+
+    fn to_markdown(&self) -> String {
+        let escape = |text: &str| text.replace('|', "\\|");
+
+        let mut markdown = format!("| {} |", tr!("cli-stat-language"));
+        for module in &self.modules {
+            markdown.push_str(&format!(" {} |", escape(module)));
+        }
+        markdown.push_str(&format!(" {} |\n| --- |", tr!("cli-stat-overall")));
+        markdown.push_str(&" ---: |".repeat(self.modules.len() + 1));
+        markdown.push('\n');
+
+        for (label, row) in self.rows() {
+            markdown.push_str(&format!("| {} |", escape(&label)));
+            for completion in self
+                .modules
+                .iter()
+                .map(|module| row.modules.get(module))
+                .chain([Some(&row.overall)])
+            {
+                match completion {
+                    None => markdown.push_str(" - |"),
+                    Some(completion) => {
+                        markdown.push_str(&format!(" {:.1}% |", completion.percent_complete))
+                    }
+                }
+            }
+            markdown.push('\n');
+        }
+
+        markdown
+    }
+
+    fn to_html(&self) -> String {
+        let title = escape_html(&tr!("cli-stat-dashboard-title"));
+        let overall = escape_html(&tr!("cli-stat-overall"));
+
+        let mut html = format!(
+            r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ font-family: sans-serif; margin: 2em; }}
+table {{ border-collapse: collapse; }}
+th, td {{ border: 1px solid #ccc; padding: 0.4em 0.8em; }}
+td {{ text-align: right; }}
+th:first-child, .overall {{ font-weight: bold; }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<table>
+<tr><th>{}</th>"#,
+            escape_html(&tr!("cli-stat-language"))
+        );
+        for module in &self.modules {
+            html.push_str(&format!("<th>{}</th>", escape_html(module)));
+        }
+        html.push_str(&format!("<th>{overall}</th></tr>\n"));
+
+        for (label, row) in self.rows() {
+            let class = match row.language {
+                None => r#" class="overall""#,
+                Some(_) => "",
+            };
+            html.push_str(&format!("<tr{class}><th>{}</th>", escape_html(&label)));
+            for completion in self
+                .modules
+                .iter()
+                .map(|module| row.modules.get(module))
+                .chain([Some(&row.overall)])
+            {
+                match completion {
+                    None => html.push_str("<td>-</td>"),
+                    // From red (0%) to green (100%)
+                    Some(completion) => html.push_str(&format!(
+                        r#"<td style="background-color: hsl({:.0}, 70%, 80%)" title="{} / {}">{:.1}%</td>"#,
+                        completion.percent_complete * 1.2,
+                        completion.finished,
+                        completion.in_use,
+                        completion.percent_complete
+                    )),
+                }
+            }
+            html.push_str("</tr>\n");
+        }
+
+        html.push_str("</table>\n</body>\n</html>\n");
+        html
+    }
+}
+
+fn language_label(row: &DashboardRow) -> String {
+    match row.language.as_deref() {
+        None | Some("") => tr!("cli-stat-no-language"),
+        Some(language) => language.to_owned(),
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod dashboard_test {
+    use super::*;
+    use crate::commands::stat::{stats_ts_node, ts_file_paths};
+    use crate::ts;
+
+    #[test]
+    fn test_module_name() {
+        assert_eq!(module_name("translations/app_de.ts", "de"), "app");
+        assert_eq!(module_name("app-pt_BR.ts", "pt_BR"), "app");
+        assert_eq!(module_name("app.zh_cn.ts", "zh_CN"), "app");
+        assert_eq!(module_name("de.ts", "de"), "de");
+        assert_eq!(module_name("app.ts", ""), "app");
+    }
+
+    #[test]
+    fn test_dashboard() {
+        let paths =
+            ts_file_paths(&["./test_data/dashboard".to_owned()]).expect("Directory is readable");
+        assert_eq!(
+            paths,
+            [
+                "./test_data/dashboard/app_de.ts",
+                "./test_data/dashboard/app_fr.ts",
+                "./test_data/dashboard/dialogs/dialogs_de.ts"
+            ]
+        );
+
+        let files = paths
+            .iter()
+            .map(|path| {
+                let ts_node = ts::read_from_file(path).expect("Test data could be loaded");
+                (
+                    path,
+                    ts_node.language.clone().unwrap_or_default(),
+                    stats_ts_node(&ts_node),
+                )
+            })
+            .collect::<Vec<_>>();
+        let dashboard = Dashboard::new(
+            files
+                .iter()
+                .map(|(path, language, stats)| (path.as_str(), language.as_str(), stats)),
+        );
+
+        assert_eq!(dashboard.modules, ["app", "dialogs"]);
+        assert_eq!(dashboard.languages[1].language.as_deref(), Some("fr"));
+        assert!(!dashboard.languages[1].modules.contains_key("dialogs"));
+        assert_eq!(dashboard.overall.overall, Completion::new(4, 7));
+
+        assert_eq!(
+            dashboard.format(StatFormat::Text).expect("Formattable"),
+            r#"Language       app   dialogs   Overall
+de           66.7%    100.0%     75.0%
+fr           33.3%         -     33.3%
+Overall      50.0%    100.0%     57.1%
+"#
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&dashboard.format(StatFormat::Json).expect("Formattable"))
+                .expect("Valid JSON");
+        assert_eq!(
+            json["languages"][0]["modules"]["app"]["percent_complete"],
+            66.7
+        );
+        assert_eq!(json["overall"]["modules"]["app"]["in_use"], 6);
+
+        let html = dashboard.format(StatFormat::Html).expect("Formattable");
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains(r#"<tr><th>fr</th><td style="background-color: hsl(40, 70%, 80%)" title="1 / 3">33.3%</td><td>-</td>"#));
+
+        let csv = dashboard.format(StatFormat::Csv).expect("Formattable");
+        assert!(
+            csv.starts_with("language,module,finished,in_use,percent_complete\nde,app,2,3,66.7\n")
+        );
+
+        assert_eq!(
+            dashboard.format(StatFormat::Markdown).expect("Formattable"),
+            r#"| Language | app | dialogs | Overall |
+| --- | ---: | ---: | ---: |
+| de | 66.7% | 100.0% | 75.0% |
+| fr | 33.3% | - | 33.3% |
+| Overall | 50.0% | 100.0% | 57.1% |
+"#
+        );
+    }
+}
@@ -0,0 +1,735 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::AddAssign;
+use std::path::{Path, PathBuf};
+use std::string::ToString;
+
+use clap::{ArgAction, Args};
+use log::debug;
+use serde::Serialize;
+
+use crate::commands::report::write_to_output;
+use crate::commands::stat::dashboard::Dashboard;
+use crate::commands::stat::output::{StatFormat, format_stats};
+use crate::commands::stat::thresholds::{Thresholds, parse_percent};
+use crate::commands::text::extract_words;
+use crate::commands::text::translation_text;
+use crate::config::current_config;
+use crate::tr;
+use crate::ts;
+use crate::ts::{MessageNode, MessageStatus, TSNode};
+
+mod dashboard;
+mod output;
+mod thresholds;
+
+#[derive(Args)]
+#[command(disable_help_flag = true)]
+pub struct StatArgs {
+    /// File paths to get stats on, directories of TS files, or `@group` for a group of the
+    /// configuration.
+    #[arg(required = true, num_args = 1.., help = tr!("cli-stat-input"), help_heading = tr!("cli-headers-arguments"))]
+    pub input_paths: Vec<String>,
+    /// If set to true, will prepend a list of all unique file paths found.
+    #[arg(short, long, help = tr!("cli-stat-verbose"), help_heading = tr!("cli-headers-options"), action = ArgAction::SetTrue)]
+    pub verbose: bool,
+    /// If set to true, will append the statistics of each context.
+    #[arg(short, long, help = tr!("cli-stat-contexts"), help_heading = tr!("cli-headers-options"), action = ArgAction::SetTrue)]
+    pub contexts: bool,
+    /// Order of the contexts statistics.
+    #[arg(long, value_enum, default_value_t = ContextOrder::File, help = tr!("cli-stat-sort-contexts"), help_heading = tr!("cli-headers-options"))]
+    pub sort_contexts: ContextOrder,
+    /// Format of the statistics.
+    #[arg(short, long, value_enum, default_value_t = StatFormat::Text, help = tr!("cli-stat-format"), help_heading = tr!("cli-headers-options"))]
+    pub format: StatFormat,
+    /// Fails when a file has a lower percentage of finished messages, e.g. `95`.
+    #[arg(long, value_name = "PERCENT", value_parser = parse_percent, help = tr!("cli-stat-fail-under"), help_heading = tr!("cli-headers-options"))]
+    pub fail_under: Option<f64>,
+    /// Fails when a file has more unfinished or missing translations.
+    #[arg(long, value_name = "COUNT", help = tr!("cli-stat-max-unfinished"), help_heading = tr!("cli-headers-options"))]
+    pub max_unfinished: Option<usize>,
+    /// Fails when a file has vanished translations.
+    #[arg(long, help = tr!("cli-stat-no-vanished"), help_heading = tr!("cli-headers-options"), action = ArgAction::SetTrue)]
+    pub no_vanished: bool,
+    /// If specified, will produce output in a file at designated location instead of stdout.
+    #[arg(short, long, help = tr!("cli-stat-output"), help_heading = tr!("cli-headers-options"))]
+    pub output_path: Option<String>,
+    #[arg(short, long, action = ArgAction::Help, help = tr!("cli-help"), help_heading = tr!("cli-headers-options"))]
+    pub help: Option<bool>,
+}
+
+/// Aggregates the stats for provided file and arguments. Several files, directories or groups
+/// are summarized in a dashboard of completion by language and module. Fails when a file does not
+/// meet the thresholds.
+pub fn stat_main(args: &StatArgs) -> Result<(), String> {
+    let is_single_file = matches!(
+        args.input_paths.as_slice(),
+        [path] if !path.starts_with('@') && !Path::new(path).is_dir()
+    );
+    let is_dashboard = !is_single_file || args.format == StatFormat::Html;
+    if is_dashboard && (args.verbose || args.contexts || args.sort_contexts != ContextOrder::File) {
+        return Err(tr!("cli-stat-dashboard-options"));
+    }
+
+    let mut files = vec![];
+    for path in ts_file_paths(&args.input_paths)? {
+        let ts_node = ts::read_from_file(&path)?;
+        let language = ts_node.language.clone().unwrap_or_default();
+        files.push((path, language, stats_ts_node(&ts_node)));
+    }
+    if args.sort_contexts == ContextOrder::Completion {
+        files
+            .iter_mut()
+            .for_each(|(_, _, stats)| stats.sort_contexts_by_completion());
+    }
+    let files_iter = || {
+        files
+            .iter()
+            .map(|(path, language, stats)| (path.as_str(), language.as_str(), stats))
+    };
+
+    let output = if !is_dashboard {
+        format_stats(&files[0].2, args.format, args.verbose, args.contexts)?
+    } else {
+        Dashboard::new(files_iter()).format(args.format)?
+    };
+
+    match &args.output_path {
+        None => print!("{output}"),
+        Some(output_path) => write_to_output(output_path, &output)?,
+    }
+
+    let violations = Thresholds::new(args).check(files_iter());
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        let count = violations.len();
+        Err(format!(
+            "{}\n{}",
+            tr!("cli-stat-thresholds-failed", count = count),
+            violations.join("\n")
+        ))
+    }
+}
+
+/// Expands the input paths: `@group` designates every file of a group, and a directory every TS
+/// file within it, recursively.
+fn ts_file_paths(inputs: &[String]) -> Result<Vec<String>, String> {
+    let config = current_config();
+    let mut paths = vec![];
+    let mut visited = HashSet::new();
+
+    for input in inputs {
+        for path in config.expand_input(input)? {
+            if Path::new(&path).is_dir() {
+                collect_ts_files(Path::new(&path), &mut paths, &mut visited)?;
+            } else {
+                paths.push(path);
+            }
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Collects the TS files of the directory and its subdirectories. Directories are visited once,
+/// so that symbolic links to a parent directory do not loop.
+fn collect_ts_files(
+    directory: &Path,
+    paths: &mut Vec<String>,
+    visited: &mut HashSet<PathBuf>,
+) -> Result<(), String> {
+    let read_error = |e: std::io::Error| {
+        tr!(
+            "error-open-or-parse",
+            file = directory.display().to_string(),
+            error = e.to_string()
+        )
+    };
+
+    if !visited.insert(directory.canonicalize().map_err(read_error)?) {
+        debug!("Skipping '{}', already visited", directory.display());
+        return Ok(());
+    }
+
+    let mut entries = std::fs::read_dir(directory)
+        .map_err(read_error)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(read_error)?;
+    entries.sort();
+
+    for entry in entries {
+        if entry.is_dir() {
+            collect_ts_files(&entry, paths, visited)?;
+        } else if entry.extension().is_some_and(|extension| extension == "ts") {
+            paths.push(entry.to_string_lossy().into_owned());
+        }
+    }
+
+    Ok(())
+}
+
+/// Progress of a message, shared by every statistic. A message in use which is not finished is
+/// missing when it has no translation text, and unfinished otherwise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Progress {
+    Finished,
+    Unfinished,
+    Missing,
+    Obsolete,
+    Vanished,
+}
+
+impl Progress {
+    const ALL: [Progress; 5] = [
+        Progress::Finished,
+        Progress::Unfinished,
+        Progress::Missing,
+        Progress::Obsolete,
+        Progress::Vanished,
+    ];
+
+    fn of(message: &MessageNode) -> Self {
+        match message.status() {
+            MessageStatus::Obsolete => Progress::Obsolete,
+            MessageStatus::Vanished => Progress::Vanished,
+            _ if translation_text(message).is_none() => Progress::Missing,
+            MessageStatus::Finished => Progress::Finished,
+            MessageStatus::Unfinished => Progress::Unfinished,
+        }
+    }
+
+    /// Stable name, used in metric names.
+    fn name(self) -> &'static str {
+        match self {
+            Progress::Finished => "finished",
+            Progress::Unfinished => "unfinished",
+            Progress::Missing => "missing",
+            Progress::Obsolete => "obsolete",
+            Progress::Vanished => "vanished",
+        }
+    }
+}
+
+/// Order in which the contexts statistics are listed.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum ContextOrder {
+    /// Order of the contexts in the file
+    #[default]
+    File,
+    /// Least complete contexts first
+    Completion,
+}
+
+#[derive(Clone, Default, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+struct FileStats {
+    pub filepath: String,
+    pub unfinished_translations: usize,
+    pub missing_translations: usize,
+    pub vanished_translations: usize,
+    pub obsolete_translations: usize,
+    #[serde(rename = "finished_translations")]
+    pub finished_translation: usize,
+    /// For files, total_translations corresponds to number of time that file was
+    /// mentioned as a location.
+    pub total_translations: usize,
+}
+
+#[derive(Default, Serialize)]
+struct TotalStats {
+    // Translation block
+    /// Messages in use without translation text
+    pub total_missing_translations: usize,
+    /// Messages in use with a translation text, which is not finished
+    pub total_unfinished_translations: usize,
+    pub total_vanished_translations: usize,
+    pub total_obsolete_translations: usize,
+    /// Corresponds to the number of unique translation
+    /// This is the sum of obsolete, vanished, missing and complete translations.
+    pub total_unique_translations: usize,
+    /// Corresponds to the number of references in files.
+    /// For example, if a translation is the same for 3 files, it will return 3, not 1.
+    /// Even if 2 locations is in the same file, it will count as 2.
+    pub total_translations_references: usize,
+    pub total_contexts: usize,
+    pub total_messages: usize,
+
+    /// Statistics by file
+    pub files: Vec<FileStats>,
+    /// Size of the sources, by status of their translation
+    pub source_size: StatusSizes,
+    /// Statistics by context
+    pub contexts: Vec<ContextStats>,
+}
+
+impl TotalStats {
+    /// Sorts the contexts from the least complete, keeping the file order of equally complete ones.
+    fn sort_contexts_by_completion(&mut self) {
+        self.contexts
+            .sort_by(|a, b| a.percent_complete.total_cmp(&b.percent_complete));
+    }
+}
+
+#[derive(Default, Serialize)]
+struct ContextStats {
+    pub name: String,
+    pub finished: usize,
+    /// Translated, but not marked as finished.
+    pub unfinished: usize,
+    /// Not translated at all.
+    pub missing: usize,
+    pub obsolete: usize,
+    pub vanished: usize,
+    /// Percentage of the finished messages among the messages still in use, which are neither
+    /// obsolete nor vanished. Rounded to one decimal.
+    pub percent_complete: f64,
+    /// Size of the sources, by status of their translation
+    pub source_size: StatusSizes,
+}
+
+/// Size of source texts, as billed by translation agencies. Placeholders, markup, entities and
+/// accelerators are not counted, and characters are those of the words, without spaces nor
+/// punctuation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+struct SourceSize {
+    pub words: usize,
+    pub characters: usize,
+}
+
+impl SourceSize {
+    fn new(source: &str) -> Self {
+        let words = extract_words(source);
+        SourceSize {
+            words: words.len(),
+            characters: words.iter().map(|word| word.chars().count()).sum(),
+        }
+    }
+}
+
+impl AddAssign<SourceSize> for SourceSize {
+    fn add_assign(&mut self, other: SourceSize) {
+        self.words += other.words;
+        self.characters += other.characters;
+    }
+}
+
+/// Source sizes by status of the translation. Numerus messages have a single source, counted once.
+#[derive(Debug, Default, PartialEq, Serialize)]
+struct StatusSizes {
+    pub finished: SourceSize,
+    pub unfinished: SourceSize,
+    pub missing: SourceSize,
+    pub obsolete: SourceSize,
+    pub vanished: SourceSize,
+    pub total: SourceSize,
+}
+
+impl AddAssign<&StatusSizes> for StatusSizes {
+    fn add_assign(&mut self, other: &StatusSizes) {
+        self.finished += other.finished;
+        self.unfinished += other.unfinished;
+        self.missing += other.missing;
+        self.obsolete += other.obsolete;
+        self.vanished += other.vanished;
+        self.total += other.total;
+    }
+}
+
+impl StatusSizes {
+    fn get(&self, progress: Progress) -> SourceSize {
+        match progress {
+            Progress::Finished => self.finished,
+            Progress::Unfinished => self.unfinished,
+            Progress::Missing => self.missing,
+            Progress::Obsolete => self.obsolete,
+            Progress::Vanished => self.vanished,
+        }
+    }
+
+    fn get_mut(&mut self, progress: Progress) -> &mut SourceSize {
+        match progress {
+            Progress::Finished => &mut self.finished,
+            Progress::Unfinished => &mut self.unfinished,
+            Progress::Missing => &mut self.missing,
+            Progress::Obsolete => &mut self.obsolete,
+            Progress::Vanished => &mut self.vanished,
+        }
+    }
+
+    /// Sources still to translate: the missing and unfinished translations.
+    fn left(&self) -> SourceSize {
+        let mut left = self.missing;
+        left += self.unfinished;
+        left
+    }
+}
+
+impl ContextStats {
+    fn count(&self, progress: Progress) -> usize {
+        match progress {
+            Progress::Finished => self.finished,
+            Progress::Unfinished => self.unfinished,
+            Progress::Missing => self.missing,
+            Progress::Obsolete => self.obsolete,
+            Progress::Vanished => self.vanished,
+        }
+    }
+
+    fn count_mut(&mut self, progress: Progress) -> &mut usize {
+        match progress {
+            Progress::Finished => &mut self.finished,
+            Progress::Unfinished => &mut self.unfinished,
+            Progress::Missing => &mut self.missing,
+            Progress::Obsolete => &mut self.obsolete,
+            Progress::Vanished => &mut self.vanished,
+        }
+    }
+
+    fn new(name: &str, messages: &[MessageNode]) -> Self {
+        let mut stats = ContextStats {
+            name: name.to_owned(),
+            ..ContextStats::default()
+        };
+
+        for message in messages {
+            let progress = Progress::of(message);
+            let source_size = SourceSize::new(message.source.as_deref().unwrap_or_default());
+            stats.count_mut(progress).add_assign(1);
+            stats.source_size.get_mut(progress).add_assign(source_size);
+            stats.source_size.total.add_assign(source_size);
+        }
+
+        stats.percent_complete = percent_complete(
+            stats.finished,
+            stats.finished + stats.unfinished + stats.missing,
+        );
+
+        stats
+    }
+}
+
+/// Percentage of finished messages among the messages in use, rounded to one decimal. Nothing to
+/// translate is complete.
+fn percent_complete(finished: usize, in_use: usize) -> f64 {
+    match in_use {
+        0 => 100.0,
+        _ => (finished as f64 * 1000.0 / in_use as f64).round() / 10.0,
+    }
+}
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+enum FileKey<'a> {
+    Invalid,
+    Valid(&'a String),
+}
+
+fn stats_ts_node(ts_node: &TSNode) -> TotalStats {
+    let mut stats = TotalStats {
+        total_contexts: ts_node.contexts.len(),
+        ..TotalStats::default()
+    };
+
+    let mut files_stats = HashMap::<FileKey, FileStats>::new();
+
+    for context in &ts_node.contexts {
+        stats.total_messages.add_assign(context.messages.len());
+        stats_for_messages(&context.messages, &mut stats, &mut files_stats);
+        let context_stats = ContextStats::new(&context.name, &context.messages);
+        stats.source_size.add_assign(&context_stats.source_size);
+        stats.contexts.push(context_stats);
+    }
+
+    stats.files = files_stats.values().cloned().collect();
+    stats.files.sort();
+
+    stats
+}
+
+fn stats_for_messages<'a>(
+    messages: &'a [MessageNode],
+    stats: &mut TotalStats,
+    files_stats: &mut HashMap<FileKey<'a>, FileStats>,
+) {
+    for message in messages {
+        let progress = Progress::of(message);
+        if message.translation.is_some() {
+            stats.total_unique_translations.add_assign(1);
+        }
+
+        stats
+            .total_translations_references
+            .add_assign(message.locations.len());
+
+        for location in &message.locations {
+            // Filename _may_ be empty, although it should not really happen.
+            // We can report this as an error ?
+            let file_key = match location.filename.as_ref() {
+                None => FileKey::Invalid,
+                Some(path) => FileKey::Valid(path),
+            };
+
+            // Note: there could be duplicate in locations, right ?
+            let file = &mut files_stats.entry(file_key.clone()).or_default();
+            file.total_translations.add_assign(1);
+            file.filepath = match file_key {
+                FileKey::Invalid => "invalid".to_owned(),
+                FileKey::Valid(path) => path.clone(),
+            };
+
+            match progress {
+                Progress::Finished => file.finished_translation.add_assign(1),
+                Progress::Unfinished => file.unfinished_translations.add_assign(1),
+                Progress::Missing => file.missing_translations.add_assign(1),
+                Progress::Obsolete => file.obsolete_translations.add_assign(1),
+                Progress::Vanished => file.vanished_translations.add_assign(1),
+            }
+        }
+
+        match progress {
+            Progress::Finished => {}
+            Progress::Unfinished => stats.total_unfinished_translations.add_assign(1),
+            Progress::Missing => stats.total_missing_translations.add_assign(1),
+            Progress::Obsolete => stats.total_obsolete_translations.add_assign(1),
+            Progress::Vanished => stats.total_vanished_translations.add_assign(1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod stats_tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_aggregate() {
+        let data_nostats: TSNode = {
+            let reader_stats = quick_xml::Reader::from_file("./test_data/example_stats.xml")
+                .expect("Test file is readable");
+            quick_xml::de::from_reader(reader_stats.into_inner()).expect("Parsable")
+        };
+
+        let stats = stats_ts_node(&data_nostats);
+
+        // Per file: a translation count is the number of location for a translation for a file.
+        // Total: simply the number of time a translation appear in a message
+        assert_eq!(stats.total_contexts, 1);
+        assert_eq!(stats.total_messages, 11);
+        assert_eq!(stats.total_obsolete_translations, 3);
+        assert_eq!(stats.total_vanished_translations, 1);
+        assert_eq!(stats.total_missing_translations, 0);
+        assert_eq!(stats.total_unique_translations, 11);
+        // One node has 2 locations
+        assert_eq!(stats.total_translations_references, 12);
+
+        let files = &stats.files;
+
+        assert_eq!(
+            files
+                .iter()
+                .filter(|entry| entry.filepath == "tst_qkeysequence.cpp")
+                .count(),
+            1
+        );
+        assert_eq!(
+            files
+                .iter()
+                .filter(|entry| entry.filepath == "tst_nostrip.cpp")
+                .count(),
+            1
+        );
+        assert_eq!(
+            files
+                .iter()
+                .filter(|entry| entry.filepath == "tst_nostrip2.cpp")
+                .count(),
+            0
+        );
+
+        let nostrip = files.first().expect("Has index 0 entry");
+        assert_eq!(nostrip.filepath, "tst_nostrip.cpp");
+        assert_eq!(nostrip.total_translations, 1);
+        assert_eq!(nostrip.vanished_translations, 0);
+        assert_eq!(nostrip.unfinished_translations, 0);
+        assert_eq!(nostrip.obsolete_translations, 0);
+
+        let nostrip2 = files.get(1).expect("Has index 1 entry");
+        assert_eq!(nostrip2.filepath, "tst_qkeysequence.cpp");
+        assert_eq!(nostrip2.total_translations, 11);
+        assert_eq!(nostrip2.vanished_translations, 1);
+        assert_eq!(nostrip2.unfinished_translations, 0);
+        assert_eq!(nostrip2.obsolete_translations, 4);
+    }
+
+    #[test]
+    fn test_stats_formats() {
+        let data: TSNode = {
+            let reader_stats = quick_xml::Reader::from_file("./test_data/example_stats.xml")
+                .expect("Test file is readable");
+            quick_xml::de::from_reader(reader_stats.into_inner()).expect("Parsable")
+        };
+        let stats = stats_ts_node(&data);
+
+        let json: serde_json::Value = serde_json::from_str(
+            &format_stats(&stats, StatFormat::Json, false, false).expect("Formattable"),
+        )
+        .expect("Valid JSON");
+        assert_eq!(json["total_obsolete_translations"], 3);
+        assert_eq!(json["files"][1]["filepath"], "tst_qkeysequence.cpp");
+        assert_eq!(json["files"][1]["obsolete_translations"], 4);
+
+        let csv = format_stats(&stats, StatFormat::Csv, false, false).expect("Formattable");
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("scope,name,metric,value"));
+        assert!(lines.any(|line| line == "total,,translations_references,12"));
+        assert!(csv.contains("file,tst_qkeysequence.cpp,vanished_translations,1\n"));
+
+        let markdown =
+            format_stats(&stats, StatFormat::Markdown, true, false).expect("Formattable");
+        assert!(markdown.contains("| Messages | 11 |\n"));
+        assert!(markdown.contains("| tst_qkeysequence.cpp | 11 | 0 | 0 | 6 | 4 | 1 |\n"));
+        assert!(
+            !format_stats(&stats, StatFormat::Markdown, false, false)
+                .expect("Formattable")
+                .contains("tst_qkeysequence.cpp")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_ts_file_paths_symlink_loop() {
+        const TEST_DIRECTORY: &str = "./test_data/test_result_symlink_loop";
+
+        let _ = std::fs::remove_dir_all(TEST_DIRECTORY);
+        std::fs::create_dir_all(format!("{TEST_DIRECTORY}/nested")).expect("Directory is created");
+        std::fs::copy(
+            "./test_data/dashboard/app_de.ts",
+            format!("{TEST_DIRECTORY}/nested/app_de.ts"),
+        )
+        .expect("Test file is copied");
+        std::os::unix::fs::symlink("..", format!("{TEST_DIRECTORY}/nested/parent"))
+            .expect("Symbolic link is created");
+
+        let paths = ts_file_paths(&[TEST_DIRECTORY.to_owned()]);
+        std::fs::remove_dir_all(TEST_DIRECTORY).expect("Test should clean test directory.");
+
+        assert_eq!(
+            paths.expect("Directory is readable"),
+            [format!("{TEST_DIRECTORY}/nested/app_de.ts")]
+        );
+    }
+
+    #[test]
+    fn test_stats_contexts() {
+        let data: TSNode = {
+            let reader_stats =
+                quick_xml::Reader::from_file("./test_data/example_stats_contexts.xml")
+                    .expect("Test file is readable");
+            quick_xml::de::from_reader(reader_stats.into_inner()).expect("Parsable")
+        };
+        let mut stats = stats_ts_node(&data);
+
+        let names = |stats: &TotalStats| {
+            stats
+                .contexts
+                .iter()
+                .map(|context| context.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            names(&stats),
+            ["AboutDialog", "MainWindow", "SettingsDialog"]
+        );
+
+        let main_window = &stats.contexts[1];
+        assert_eq!(main_window.finished, 1);
+        assert_eq!(main_window.unfinished, 1);
+        assert_eq!(main_window.missing, 1);
+        assert_eq!(main_window.obsolete, 1);
+        assert_eq!(main_window.vanished, 0);
+        assert_eq!(main_window.percent_complete, 33.3);
+
+        let settings = &stats.contexts[2];
+        assert_eq!(settings.finished, 2);
+        assert_eq!(settings.missing, 1);
+        assert_eq!(settings.vanished, 1);
+        assert_eq!(settings.percent_complete, 66.7);
+
+        // The summary counts untranslated messages as missing, like the contexts do
+        assert_eq!(stats.total_missing_translations, 2);
+        assert_eq!(stats.total_unfinished_translations, 1);
+
+        stats.sort_contexts_by_completion();
+        assert_eq!(
+            names(&stats),
+            ["MainWindow", "SettingsDialog", "AboutDialog"]
+        );
+
+        let text = format_stats(&stats, StatFormat::Text, false, true).expect("Formattable");
+        assert!(text.ends_with(
+            r#"Context                            Finished Unfinished    Missing   Obsolete   Vanished      Words Words left   Complete
+MainWindow                                1          1          1          1          0         12          8      33.3%
+SettingsDialog                            2          0          1          0          1          9          3      66.7%
+AboutDialog                               2          0          0          0          0          6          0     100.0%
+"#
+        ));
+        assert!(
+            !format_stats(&stats, StatFormat::Text, false, false)
+                .expect("Formattable")
+                .contains("MainWindow")
+        );
+
+        let csv = format_stats(&stats, StatFormat::Csv, false, false).expect("Formattable");
+        assert!(csv.contains("context,SettingsDialog,missing,1\n"));
+        assert!(csv.contains("context,AboutDialog,percent_complete,100\n"));
+    }
+
+    #[test]
+    fn test_stats_source_size() {
+        let data: TSNode = {
+            let reader_stats =
+                quick_xml::Reader::from_file("./test_data/example_stats_contexts.xml")
+                    .expect("Test file is readable");
+            quick_xml::de::from_reader(reader_stats.into_inner()).expect("Parsable")
+        };
+        let stats = stats_ts_node(&data);
+
+        // Neither the placeholder nor the link markup are counted
+        assert_eq!(
+            stats.contexts[0].source_size.finished,
+            SourceSize {
+                words: 6,
+                characters: 34
+            }
+        );
+        // The accelerator is not counted
+        assert_eq!(
+            stats.contexts[1].source_size.finished,
+            SourceSize {
+                words: 3,
+                characters: 9
+            }
+        );
+        // The numerus source is counted once, whatever the number of forms
+        assert_eq!(stats.contexts[2].source_size.finished.words, 5);
+        assert_eq!(stats.contexts[2].source_size.left().words, 3);
+
+        let total = &stats.source_size;
+        assert_eq!(total.finished.words, 14);
+        assert_eq!(total.unfinished.words, 4);
+        assert_eq!(total.missing.words, 7);
+        assert_eq!(total.obsolete.words, 1);
+        assert_eq!(total.vanished.words, 1);
+        assert_eq!(
+            total.total,
+            SourceSize {
+                words: 27,
+                characters: 134
+            }
+        );
+
+        let text = format_stats(&stats, StatFormat::Text, false, false).expect("Formattable");
+        assert!(text.contains("Missing                           7         39\n"));
+        let csv = format_stats(&stats, StatFormat::Csv, false, false).expect("Formattable");
+        assert!(csv.contains("total,,missing_words,7\n"));
+        assert!(csv.contains("context,MainWindow,total_characters,56\n"));
+    }
+}
@@ -0,0 +1,348 @@
+use crate::commands::report::to_json;
+use crate::commands::stat::{Progress, SourceSize, StatusSizes, TotalStats};
+use crate::tr;
+
+/// Format in which statistics are written.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum StatFormat {
+    /// Human readable summary
+    #[default]
+    Text,
+    Json,
+    /// One `scope,name,metric,value` row per statistic
+    Csv,
+    /// Markdown tables
+    Markdown,
+    /// Self-contained HTML page of the completion by language and module
+    Html,
+}
+
+pub(super) fn format_stats(
+    stats: &TotalStats,
+    format: StatFormat,
+    verbose: bool,
+    contexts: bool,
+) -> Result<String, String> {
+    match format {
+        StatFormat::Text => Ok(generate_message_for_stats(stats, verbose, contexts)),
+        StatFormat::Json => to_json(stats),
+        StatFormat::Csv => Ok(to_csv(stats)),
+        StatFormat::Markdown => Ok(to_markdown(stats, verbose, contexts)),
+        StatFormat::Html => unreachable!("HTML is rendered by the dashboard"),
+    }
+}
+
+/// Label of a progress. "Finished", "Unfinished", "Obsolete" and "Vanished" are literals in the xml
+/// file, let's not translate them. "Missing" is not one of them.
+fn progress_label(progress: Progress) -> String {
+    match progress {
+        Progress::Finished => "Finished".to_owned(),
+        Progress::Unfinished => "Unfinished".to_owned(),
+        Progress::Missing => tr!("cli-stat-missing"),
+        Progress::Obsolete => "Obsolete".to_owned(),
+        Progress::Vanished => "Vanished".to_owned(),
+    }
+}
+
+fn generate_message_for_stats(stats: &TotalStats, verbose: bool, contexts: bool) -> String {
+    let mut buf = String::new();
+
+    if verbose && !stats.files.is_empty() {
+        buf.push_str("------------------------------------------------------------------------------------------------------\n");
+        buf.push_str(&format!("{}\n", tr!("cli-stat-detailed-report")));
+        buf.push_str("------------------------------------------------------------------------------------------------------\n");
+
+        for file in &stats.files {
+            buf.push_str(&format!(
+                "{} \"{}\"\n\t{: <25}: {}\n\t{: <25}: {}\n\t{: <25}: {}\n\t{: <25}: {}\n\t{: <25}: {}\n\t{: <25}: {}\n",
+                tr!("cli-stat-filepath-header"),
+                file.filepath,
+                tr!("cli-stat-translations-refs"),
+                file.total_translations,
+                progress_label(Progress::Unfinished),
+                file.unfinished_translations,
+                progress_label(Progress::Missing),
+                file.missing_translations,
+                progress_label(Progress::Finished),
+                file.finished_translation,
+                progress_label(Progress::Obsolete),
+                file.obsolete_translations,
+                progress_label(Progress::Vanished),
+                file.vanished_translations
+            ));
+        }
+    }
+
+    buf.push_str("------------------------------------------------------------------------------------------------------\n");
+    buf.push_str(&format!("{}\n", tr!("cli-stat-file-summary")));
+    buf.push_str("------------------------------------------------------------------------------------------------------\n");
+    buf.push_str(&format!(
+        "{: <24} : {}\n",
+        tr!("cli-stat-files"),
+        stats.files.len()
+    ));
+    buf.push_str(&format!("{: <24} : {}\n", "Contexts", stats.total_contexts));
+    buf.push_str(&format!("{: <24} : {}\n", "Messages", stats.total_messages));
+    buf.push_str(&format!(
+        "{: <24} : {}\n",
+        tr!("cli-stat-unique-translations"),
+        stats.total_unique_translations
+    ));
+    buf.push_str(&format!(
+        "{: <24} : {}\n",
+        tr!("cli-stat-translations-refs"),
+        stats.total_translations_references
+    ));
+    for (_, label, value) in &total_metrics(stats)[5..] {
+        buf.push_str(&format!("{label: <24} : {value}\n"));
+    }
+
+    buf.push_str("------------------------------------------------------------------------------------------------------\n");
+    buf.push_str(&format!(
+        "{: <24} {: >10} {: >10}\n",
+        tr!("cli-stat-source-size"),
+        tr!("cli-stat-words"),
+        tr!("cli-stat-characters")
+    ));
+    for (label, size) in size_labels(&stats.source_size) {
+        buf.push_str(&format!(
+            "{: <24} {: >10} {: >10}\n",
+            label, size.words, size.characters
+        ));
+    }
+
+    if contexts && !stats.contexts.is_empty() {
+        buf.push_str("------------------------------------------------------------------------------------------------------\n");
+        buf.push_str(&format!("{: <32}", tr!("cli-stat-context")));
+        for progress in Progress::ALL {
+            buf.push_str(&format!(" {: >10}", progress_label(progress)));
+        }
+        buf.push_str(&format!(
+            " {: >10} {: >10} {: >10}\n",
+            tr!("cli-stat-words"),
+            tr!("cli-stat-words-left"),
+            tr!("cli-stat-complete")
+        ));
+
+        for context in &stats.contexts {
+            buf.push_str(&format!("{: <32}", context.name));
+            for progress in Progress::ALL {
+                buf.push_str(&format!(" {: >10}", context.count(progress)));
+            }
+            buf.push_str(&format!(
+                " {: >10} {: >10}",
+                context.source_size.total.words,
+                context.source_size.left().words
+            ));
+            buf.push_str(&format!(
+                " {: >10}\n",
+                format!("{:.1}%", context.percent_complete)
+            ));
+        }
+    }
+
+    buf
+}
+
+/// Statistics of the whole file, with their stable metric name and their label.
+fn total_metrics(stats: &TotalStats) -> Vec<(&'static str, String, usize)> {
+    vec![
+        ("files", tr!("cli-stat-files"), stats.files.len()),
+        ("contexts", "Contexts".to_owned(), stats.total_contexts),
+        ("messages", "Messages".to_owned(), stats.total_messages),
+        (
+            "unique_translations",
+            tr!("cli-stat-unique-translations"),
+            stats.total_unique_translations,
+        ),
+        (
+            "translations_references",
+            tr!("cli-stat-translations-refs"),
+            stats.total_translations_references,
+        ),
+        (
+            "unfinished_translations",
+            tr!(
+                "cli-stat-type-translations",
+                ttype = progress_label(Progress::Unfinished)
+            ),
+            stats.total_unfinished_translations,
+        ),
+        (
+            "missing_translations",
+            tr!(
+                "cli-stat-type-translations",
+                ttype = progress_label(Progress::Missing)
+            ),
+            stats.total_missing_translations,
+        ),
+        (
+            "obsolete_translations",
+            tr!(
+                "cli-stat-type-translations",
+                ttype = progress_label(Progress::Obsolete)
+            ),
+            stats.total_obsolete_translations,
+        ),
+        (
+            "vanished_translations",
+            tr!(
+                "cli-stat-type-translations",
+                ttype = progress_label(Progress::Vanished)
+            ),
+            stats.total_vanished_translations,
+        ),
+    ]
+}
+
+/// Source sizes by progress, then in total, with their label.
+fn size_labels(sizes: &StatusSizes) -> Vec<(String, SourceSize)> {
+    Progress::ALL
+        .into_iter()
+        .map(|progress| (progress_label(progress), sizes.get(progress)))
+        .chain([(tr!("cli-stat-total"), sizes.total)])
+        .collect()
+}
+
+/// Long format, which charting tools pivot easily: the whole file is the `total` scope, each
+/// source file a `file` scope and each context a `context` scope.
+fn to_csv(stats: &TotalStats) -> String {
+    let mut csv = String::from("scope,name,metric,value\n");
+
+    for (metric, _, value) in total_metrics(stats) {
+        csv.push_str(&format!("total,,{metric},{value}\n"));
+    }
+    csv.push_str(&size_rows("total", "", &stats.source_size));
+
+    for file in &stats.files {
+        let name = escape_csv(&file.filepath);
+        for (metric, value) in [
+            ("translations_references", file.total_translations),
+            ("unfinished_translations", file.unfinished_translations),
+            ("missing_translations", file.missing_translations),
+            ("finished_translations", file.finished_translation),
+            ("obsolete_translations", file.obsolete_translations),
+            ("vanished_translations", file.vanished_translations),
+        ] {
+            csv.push_str(&format!("file,{name},{metric},{value}\n"));
+        }
+    }
+
+    for context in &stats.contexts {
+        let name = escape_csv(&context.name);
+        for progress in Progress::ALL {
+            csv.push_str(&format!(
+                "context,{name},{},{}\n",
+                progress.name(),
+                context.count(progress)
+            ));
+        }
+        csv.push_str(&format!(
+            "context,{name},percent_complete,{}\n",
+            context.percent_complete
+        ));
+        csv.push_str(&size_rows("context", &name, &context.source_size));
+    }
+
+    csv
+}
+
+/// Words and characters rows, e.g. `missing_words` and `total_characters`.
+fn size_rows(scope: &str, name: &str, sizes: &StatusSizes) -> String {
+    let mut rows = String::new();
+
+    for (status, size) in Progress::ALL
+        .into_iter()
+        .map(|progress| (progress.name(), sizes.get(progress)))
+        .chain([("total", sizes.total)])
+    {
+        rows.push_str(&format!("{scope},{name},{status}_words,{}\n", size.words));
+        rows.push_str(&format!(
+            "{scope},{name},{status}_characters,{}\n",
+            size.characters
+        ));
+    }
+
+    rows
+}
+
+pub(super) fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+fn to_markdown(stats: &TotalStats, verbose: bool, contexts: bool) -> String {
+    let mut markdown = format!("| {} | |\n| --- | ---: |\n", tr!("cli-stat-file-summary"));
+
+    for (_, label, value) in total_metrics(stats) {
+        markdown.push_str(&format!("| {label} | {value} |\n"));
+    }
+
+    markdown.push_str(&format!(
+        "\n| {} | {} | {} |\n| --- | ---: | ---: |\n",
+        tr!("cli-stat-source-size"),
+        tr!("cli-stat-words"),
+        tr!("cli-stat-characters")
+    ));
+    for (label, size) in size_labels(&stats.source_size) {
+        markdown.push_str(&format!(
+            "| {label} | {} | {} |\n",
+            size.words, size.characters
+        ));
+    }
+
+    if verbose && !stats.files.is_empty() {
+        markdown.push_str(&format!(
+            "\n| {} | {} | {} | {} | {} | {} | {} |\n| --- | ---: | ---: | ---: | ---: | ---: | ---: |\n",
+            tr!("cli-stat-filepath-header").trim_end_matches(':'),
+            tr!("cli-stat-translations-refs"),
+            progress_label(Progress::Unfinished),
+            progress_label(Progress::Missing),
+            progress_label(Progress::Finished),
+            progress_label(Progress::Obsolete),
+            progress_label(Progress::Vanished)
+        ));
+        for file in &stats.files {
+            markdown.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {} | {} |\n",
+                file.filepath.replace('|', "\\|"),
+                file.total_translations,
+                file.unfinished_translations,
+                file.missing_translations,
+                file.finished_translation,
+                file.obsolete_translations,
+                file.vanished_translations
+            ));
+        }
+    }
+
+    if contexts && !stats.contexts.is_empty() {
+        markdown.push_str(&format!("\n| {} |", tr!("cli-stat-context")));
+        for progress in Progress::ALL {
+            markdown.push_str(&format!(" {} |", progress_label(progress)));
+        }
+        markdown.push_str(&format!(
+            " {} | {} | {} |\n| --- | ---: | ---: | ---: | ---: | ---: | ---: | ---: | ---: |\n",
+            tr!("cli-stat-words"),
+            tr!("cli-stat-words-left"),
+            tr!("cli-stat-complete")
+        ));
+        for context in &stats.contexts {
+            markdown.push_str(&format!("| {} |", context.name.replace('|', "\\|")));
+            for progress in Progress::ALL {
+                markdown.push_str(&format!(" {} |", context.count(progress)));
+            }
+            markdown.push_str(&format!(
+                " {} | {} |",
+                context.source_size.total.words,
+                context.source_size.left().words
+            ));
+            markdown.push_str(&format!(" {:.1}% |\n", context.percent_complete));
+        }
+    }
+
+    markdown
+}
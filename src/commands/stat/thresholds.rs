@@ -0,0 +1,180 @@
+use crate::commands::stat::dashboard::Completion;
+use crate::commands::stat::{StatArgs, TotalStats};
+use crate::tr;
+
+/// Limits every file must respect, so that continuous integration fails on incomplete languages.
+#[derive(Debug, Default)]
+pub(super) struct Thresholds {
+    /// Minimum percentage of finished messages
+    pub fail_under: Option<f64>,
+    /// Maximum number of unfinished or missing translations
+    pub max_unfinished: Option<usize>,
+    pub no_vanished: bool,
+}
+
+impl Thresholds {
+    pub fn new(args: &StatArgs) -> Self {
+        Thresholds {
+            fail_under: args.fail_under,
+            max_unfinished: args.max_unfinished,
+            no_vanished: args.no_vanished,
+        }
+    }
+
+    /// Lists the thresholds each file does not meet, naming the file and its language.
+    pub fn check<'a>(
+        &self,
+        files: impl IntoIterator<Item = (&'a str, &'a str, &'a TotalStats)>,
+    ) -> Vec<String> {
+        let mut violations = vec![];
+
+        for (path, language, stats) in files {
+            let language = match language {
+                "" => tr!("cli-stat-no-language"),
+                language => language.to_owned(),
+            };
+            let completion = Completion::of(stats);
+            let unfinished: usize = stats
+                .contexts
+                .iter()
+                .map(|context| context.unfinished + context.missing)
+                .sum();
+            let vanished: usize = stats.contexts.iter().map(|context| context.vanished).sum();
+
+            // The percentage is rounded for display, so the exact ratio is compared
+            if let Some(threshold) = self.fail_under
+                && (completion.finished as f64) * 100.0 < threshold * completion.in_use as f64
+            {
+                // Rounded down, so that a file under the threshold is never shown at it
+                let percent = format!(
+                    "{:.1}",
+                    (completion.finished as f64 * 1000.0 / completion.in_use as f64).floor() / 10.0
+                );
+                violations.push(tr!(
+                    "cli-stat-fail-under-violation",
+                    file = path,
+                    language = language.as_str(),
+                    finished = completion.finished,
+                    total = completion.in_use,
+                    percent = percent,
+                    threshold = threshold
+                ));
+            }
+            if let Some(max) = self.max_unfinished
+                && unfinished > max
+            {
+                violations.push(tr!(
+                    "cli-stat-max-unfinished-violation",
+                    file = path,
+                    language = language.as_str(),
+                    count = unfinished,
+                    max = max
+                ));
+            }
+            if self.no_vanished && vanished > 0 {
+                violations.push(tr!(
+                    "cli-stat-no-vanished-violation",
+                    file = path,
+                    language = language.as_str(),
+                    count = vanished
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+/// Parses a `--fail-under` percentage, from 0 to 100.
+pub(super) fn parse_percent(value: &str) -> Result<f64, String> {
+    value
+        .trim_end_matches('%')
+        .parse::<f64>()
+        .ok()
+        .filter(|percent| (0.0..=100.0).contains(percent))
+        .ok_or_else(|| tr!("cli-stat-fail-under-invalid", value = value))
+}
+
+#[cfg(test)]
+mod thresholds_test {
+    use super::*;
+    use crate::commands::stat::{ContextStats, stats_ts_node};
+    use crate::ts;
+
+    #[test]
+    fn test_thresholds() {
+        let files = [
+            "./test_data/dashboard/app_de.ts",
+            "./test_data/example_stats.xml",
+        ]
+        .map(|path| {
+            let ts_node = ts::read_from_file(path).expect("Test data could be loaded");
+            (
+                path,
+                ts_node.language.clone().unwrap_or_default(),
+                stats_ts_node(&ts_node),
+            )
+        });
+        let files_iter = || {
+            files
+                .iter()
+                .map(|(path, language, stats)| (*path, language.as_str(), stats))
+        };
+
+        assert!(Thresholds::default().check(files_iter()).is_empty());
+
+        let thresholds = Thresholds {
+            fail_under: Some(95.0),
+            max_unfinished: Some(0),
+            no_vanished: true,
+        };
+        assert_eq!(
+            thresholds.check(files_iter()),
+            [
+                "./test_data/dashboard/app_de.ts (de): 2 of 3 messages finished (66.6%), under 95%",
+                "./test_data/dashboard/app_de.ts (de): 1 unfinished translations, over 0",
+                "./test_data/example_stats.xml (de): 1 vanished translations"
+            ]
+        );
+
+        let thresholds = Thresholds {
+            fail_under: Some(66.6),
+            max_unfinished: Some(1),
+            no_vanished: false,
+        };
+        assert!(thresholds.check(files_iter()).is_empty());
+    }
+
+    #[test]
+    fn test_fail_under_boundary() {
+        let stats = |finished: usize, missing: usize| TotalStats {
+            contexts: vec![ContextStats {
+                finished,
+                missing,
+                ..ContextStats::default()
+            }],
+            ..TotalStats::default()
+        };
+        let thresholds = Thresholds {
+            fail_under: Some(95.0),
+            ..Thresholds::default()
+        };
+
+        // 94.95% is displayed as 95.0%, but is under the threshold
+        let under = stats(1899, 101);
+        assert_eq!(
+            thresholds.check([("app_de.ts", "de", &under)]),
+            ["app_de.ts (de): 1899 of 2000 messages finished (94.9%), under 95%"]
+        );
+        let at = stats(1900, 100);
+        assert!(thresholds.check([("app_de.ts", "de", &at)]).is_empty());
+    }
+
+    #[test]
+    fn test_parse_percent() {
+        assert_eq!(parse_percent("95"), Ok(95.0));
+        assert_eq!(parse_percent("99.5%"), Ok(99.5));
+        assert!(parse_percent("101").is_err());
+        assert!(parse_percent("all").is_err());
+    }
+}
@@ -0,0 +1,336 @@
+use std::ops::AddAssign;
+
+use clap::{ArgAction, Args};
+use serde::Serialize;
+
+use crate::commands::diff::pair_messages;
+use crate::commands::report::write_to_output;
+use crate::commands::report::{MessageRef, to_json};
+use crate::commands::text::extract_words;
+use crate::commands::text::translation_text;
+use crate::config::current_config;
+use crate::options::{MatchStrategy, ReportFormat};
+use crate::tr;
+use crate::ts;
+use crate::ts::{MessageNode, MessageStatus, TSNode};
+
+/// Reports the translation work done between two versions of a translation file.
+#[derive(Args)]
+#[command(disable_help_flag = true)]
+pub struct StatusChangesArgs {
+    /// Previous version of the file, or `@group` for a group of a single file.
+    #[arg(help = tr!("cli-status-changes-old"), help_heading = tr!("cli-headers-arguments"))]
+    pub old_path: String,
+    /// Current version of the file, or `@group` for a group of a single file.
+    #[arg(help = tr!("cli-status-changes-new"), help_heading = tr!("cli-headers-arguments"))]
+    pub new_path: String,
+    /// How messages of both versions are identified as being the same message, within a context.
+    #[arg(short, long, value_enum, help = tr!("cli-diff-strategy"), help_heading = tr!("cli-headers-options"))]
+    pub strategy: Option<MatchStrategy>,
+    /// Format of the report.
+    #[arg(short, long, value_enum, help = tr!("cli-status-changes-format"), help_heading = tr!("cli-headers-options"))]
+    pub format: Option<ReportFormat>,
+    /// If specified, will produce output in a file at designated location instead of stdout.
+    #[arg(short, long, help = tr!("cli-status-changes-output"), help_heading = tr!("cli-headers-options"))]
+    pub output_path: Option<String>,
+    #[arg(short, long, action = ArgAction::Help, help = tr!("cli-help"), help_heading = tr!("cli-headers-options"))]
+    pub help: Option<bool>,
+}
+
+pub fn status_changes_main(args: &StatusChangesArgs) -> Result<(), String> {
+    let config = current_config();
+    let old = ts::read_from_file(&config.expand_single_input(&args.old_path)?)?;
+    let new = ts::read_from_file(&config.expand_single_input(&args.new_path)?)?;
+
+    // Both commands compare two versions of a file, so they share the `[diff]` settings
+    let strategy = args
+        .strategy
+        .or(config.diff.strategy)
+        .unwrap_or(MatchStrategy::SourceCommentContext);
+    let output = StatusChangesReport::new(&old, &new, strategy)
+        .format(args.format.or(config.diff.format).unwrap_or_default())?;
+
+    match &args.output_path {
+        None => print!("{output}"),
+        Some(output_path) => write_to_output(output_path, &output)?,
+    }
+
+    Ok(())
+}
+
+/// Messages whose status or translation changed between two versions of a translation file.
+#[derive(Serialize, Default, Debug, PartialEq)]
+pub struct StatusChangesReport {
+    newly_finished: Vec<MessageRef>,
+    newly_unfinished: Vec<MessageRef>,
+    edited: Vec<MessageRef>,
+    /// Messages which went obsolete or vanished
+    obsoleted: Vec<MessageRef>,
+    /// Messages deleted from the file, rather than marked obsolete or vanished
+    removed: Vec<MessageRef>,
+    contexts: Vec<ContextCounts>,
+    total: ChangeCounts,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+struct ContextCounts {
+    context: String,
+    #[serde(flatten)]
+    counts: ChangeCounts,
+}
+
+#[derive(Serialize, Default, Debug, PartialEq)]
+struct ChangeCounts {
+    newly_finished: usize,
+    newly_unfinished: usize,
+    edited: usize,
+    obsoleted: usize,
+    removed: usize,
+    /// Source words of the newly finished and edited messages
+    new_work_words: usize,
+}
+
+impl AddAssign<&ChangeCounts> for ChangeCounts {
+    fn add_assign(&mut self, other: &ChangeCounts) {
+        self.newly_finished += other.newly_finished;
+        self.newly_unfinished += other.newly_unfinished;
+        self.edited += other.edited;
+        self.obsoleted += other.obsoleted;
+        self.removed += other.removed;
+        self.new_work_words += other.new_work_words;
+    }
+}
+
+/// Change of a message between both versions.
+enum StatusChange {
+    NewlyFinished,
+    NewlyUnfinished,
+    Edited,
+    Obsoleted,
+    Removed,
+}
+
+fn status_change(old: Option<&MessageNode>, new: &MessageNode) -> Option<StatusChange> {
+    let old_status = old.map(MessageNode::status);
+
+    match new.status() {
+        status if !status.is_in_use() => old_status
+            .is_some_and(MessageStatus::is_in_use)
+            .then_some(StatusChange::Obsoleted),
+        status if old_status.is_some_and(|old_status| old_status != status) => match status {
+            MessageStatus::Finished => Some(StatusChange::NewlyFinished),
+            _ => Some(StatusChange::NewlyUnfinished),
+        },
+        MessageStatus::Finished if old.is_none() => Some(StatusChange::NewlyFinished),
+        _ => old
+            .filter(|old| translation_text(old) != translation_text(new))
+            .map(|_| StatusChange::Edited),
+    }
+}
+
+impl StatusChangesReport {
+    pub fn new(old: &TSNode, new: &TSNode, strategy: MatchStrategy) -> Self {
+        let mut report = StatusChangesReport::default();
+
+        for pair in pair_messages(old, new, strategy) {
+            let (message, change) = match (pair.old, pair.new) {
+                (_, Some(new_message)) => match status_change(pair.old, new_message) {
+                    Some(change) => (new_message, change),
+                    None => continue,
+                },
+                (Some(old_message), None) => (old_message, StatusChange::Removed),
+                (None, None) => continue,
+            };
+
+            if report
+                .contexts
+                .last()
+                .is_none_or(|counts| counts.context != pair.context)
+            {
+                report.contexts.push(ContextCounts {
+                    context: pair.context.to_owned(),
+                    counts: ChangeCounts::default(),
+                });
+            }
+            let counts = &mut report.contexts.last_mut().expect("Just pushed").counts;
+            let message_ref = MessageRef::new(pair.context, message);
+            let words = || extract_words(message.source.as_deref().unwrap_or_default()).len();
+
+            match change {
+                StatusChange::NewlyFinished => {
+                    counts.newly_finished += 1;
+                    counts.new_work_words += words();
+                    report.newly_finished.push(message_ref);
+                }
+                StatusChange::NewlyUnfinished => {
+                    counts.newly_unfinished += 1;
+                    report.newly_unfinished.push(message_ref);
+                }
+                StatusChange::Edited => {
+                    counts.edited += 1;
+                    counts.new_work_words += words();
+                    report.edited.push(message_ref);
+                }
+                StatusChange::Obsoleted => {
+                    counts.obsoleted += 1;
+                    report.obsoleted.push(message_ref);
+                }
+                StatusChange::Removed => {
+                    counts.removed += 1;
+                    report.removed.push(message_ref);
+                }
+            }
+        }
+
+        for context in &report.contexts {
+            report.total += &context.counts;
+        }
+
+        report
+    }
+
+    pub fn format(&self, format: ReportFormat) -> Result<String, String> {
+        match format {
+            ReportFormat::Text => Ok(self.to_text()),
+            ReportFormat::Json => to_json(self),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let message_line = |message: &MessageRef| {
+            tr!(
+                "cli-diff-message",
+                context = message.context.as_str(),
+                source = message.source.as_str()
+            )
+        };
+
+        let newly_finished = self.newly_finished.len();
+        let newly_unfinished = self.newly_unfinished.len();
+        let edited = self.edited.len();
+        let obsoleted = self.obsoleted.len();
+        let removed = self.removed.len();
+        let sections = [
+            (
+                tr!("cli-status-changes-newly-finished", count = newly_finished),
+                &self.newly_finished,
+            ),
+            (
+                tr!(
+                    "cli-status-changes-newly-unfinished",
+                    count = newly_unfinished
+                ),
+                &self.newly_unfinished,
+            ),
+            (
+                tr!("cli-status-changes-edited", count = edited),
+                &self.edited,
+            ),
+            (
+                tr!("cli-status-changes-obsoleted", count = obsoleted),
+                &self.obsoleted,
+            ),
+            (
+                tr!("cli-status-changes-removed", count = removed),
+                &self.removed,
+            ),
+        ];
+
+        let mut text = String::new();
+        for (header, messages) in sections {
+            if !messages.is_empty() {
+                text.push_str(&format!("{header}\n"));
+                messages
+                    .iter()
+                    .for_each(|message| text.push_str(&format!("  {}\n", message_line(message))));
+            }
+        }
+
+        if self.contexts.is_empty() {
+            text.push_str(&format!("{}\n", tr!("cli-status-changes-none")));
+            return text;
+        }
+
+        let row = |name: &str, counts: &ChangeCounts| {
+            format!(
+                "{: <32} {: >10} {: >10} {: >10} {: >10} {: >10} {: >10}\n",
+                name,
+                counts.newly_finished,
+                counts.newly_unfinished,
+                counts.edited,
+                counts.obsoleted,
+                counts.removed,
+                counts.new_work_words
+            )
+        };
+
+        text.push('\n');
+        text.push_str(&format!(
+            "{: <32} {: >10} {: >10} {: >10} {: >10} {: >10} {: >10}\n",
+            tr!("cli-status-changes-context"),
+            tr!("cli-status-changes-finished-header"),
+            tr!("cli-status-changes-unfinished-header"),
+            tr!("cli-status-changes-edited-header"),
+            tr!("cli-status-changes-obsoleted-header"),
+            tr!("cli-status-changes-removed-header"),
+            tr!("cli-status-changes-words")
+        ));
+        for context in &self.contexts {
+            text.push_str(&row(&context.context, &context.counts));
+        }
+        text.push_str(&row(&tr!("cli-status-changes-total"), &self.total));
+
+        text
+    }
+}
+
+#[cfg(test)]
+mod status_changes_test {
+    use super::*;
+
+    #[test]
+    fn test_status_changes() {
+        let old = ts::read_from_file("./test_data/example_status_changes_old.xml")
+            .expect("Test data could not be loaded.");
+        let new = ts::read_from_file("./test_data/example_status_changes_new.xml")
+            .expect("Test data could not be loaded.");
+
+        let report = StatusChangesReport::new(&old, &new, MatchStrategy::SourceCommentContext);
+
+        assert_eq!(
+            report.format(ReportFormat::Text).expect("Formattable"),
+            r#"Newly finished (2):
+  Context "Dialog", source "Close the window"
+  Context "MainWindow", source "Quit the application"
+Newly unfinished (1):
+  Context "Dialog", source "Open"
+Translations edited (1):
+  Context "Dialog", source "Save the <b>%1</b> file"
+Went obsolete or vanished (1):
+  Context "MainWindow", source "Print"
+Removed from the file (1):
+  Context "MainWindow", source "Export"
+
+Context                            Finished Unfinished     Edited   Obsolete    Removed      Words
+Dialog                                    1          1          1          0          0          6
+MainWindow                                1          0          0          1          1          3
+Total                                     2          1          1          1          1          9
+"#
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&report.format(ReportFormat::Json).expect("Formattable"))
+                .expect("Valid JSON");
+        assert_eq!(json["total"]["new_work_words"], 9);
+        assert_eq!(json["contexts"][1]["context"], "MainWindow");
+        assert_eq!(json["contexts"][1]["obsoleted"], 1);
+        assert_eq!(json["removed"][0]["source"], "Export");
+
+        assert_eq!(
+            StatusChangesReport::new(&old, &old, MatchStrategy::SourceCommentContext)
+                .format(ReportFormat::Text)
+                .expect("Formattable"),
+            "No status changes.\n"
+        );
+    }
+}
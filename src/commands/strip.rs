@@ -1,6 +1,7 @@
 use clap::{ArgAction, Args};
 use log::debug;
 
+use crate::config::current_config;
 use crate::locale::tr;
 use crate::ts;
 use crate::ts::{TSNode, TranslationType};
@@ -25,7 +26,7 @@ impl From<TranslationTypeArg> for TranslationType {
 #[derive(Args)]
 #[command(disable_help_flag = true)]
 pub struct StripArgs {
-    /// File path to sort translations from.
+    /// File path to strip translations from, or `@group` for a group of a single file.
     #[arg(help = tr!("cli-strip-input"), help_heading = tr!("cli-headers-arguments"))]
     pub input_path: String,
     /// Translation type list to strip from input.
@@ -39,33 +40,17 @@ pub struct StripArgs {
 }
 
 pub fn strip_main(args: &StripArgs) -> Result<(), String> {
-    match quick_xml::Reader::from_file(&args.input_path) {
-        Ok(file) => {
-            let nodes: Result<TSNode, _> = quick_xml::de::from_reader(file.into_inner());
-            match nodes {
-                Ok(mut ts_node) => {
-                    let s: Vec<TranslationType> = args
-                        .translation_type
-                        .iter()
-                        .map(|arg| arg.clone().into())
-                        .collect();
+    let input_path = current_config().expand_single_input(&args.input_path)?;
+    let mut ts_node = ts::read_from_file(&input_path)?;
 
-                    strip_nodes(&mut ts_node, &s);
-                    ts::write_to_output(&args.output_path, &ts_node)
-                }
-                Err(e) => Err(tr!(
-                    "error-ts-file-parse",
-                    file = args.input_path.as_str(),
-                    error = e.to_string()
-                )),
-            }
-        }
-        Err(e) => Err(tr!(
-            "error-open-or-parse",
-            file = args.input_path.as_str(),
-            error = e.to_string()
-        )),
-    }
+    let s: Vec<TranslationType> = args
+        .translation_type
+        .iter()
+        .map(|arg| arg.clone().into())
+        .collect();
+
+    strip_nodes(&mut ts_node, &s);
+    ts::write_to_output(&args.output_path, &ts_node)
 }
 
 fn strip_nodes(nodes: &mut TSNode, translation_type_filter: &[TranslationType]) {
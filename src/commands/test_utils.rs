@@ -22,3 +22,9 @@ pub fn read_test_file(filename: &str) -> String {
         .expect("Output to string");
     buf.replace('\r', "")
 }
+
+pub fn load_test_file(filename: &str) -> TSNode {
+    let reader = quick_xml::Reader::from_file(PathBuf::new().join("./test_data").join(filename))
+        .expect("Test file is readable");
+    quick_xml::de::from_reader(reader.into_inner()).expect("Parsable")
+}
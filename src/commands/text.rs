@@ -0,0 +1,86 @@
+use crate::ts::MessageNode;
+
+/// Text of the translation of a message, numerus forms being separated by ` | `.
+/// Returns `None` if the message is not translated.
+pub(crate) fn translation_text(message: &MessageNode) -> Option<String> {
+    let translation = message.translation.as_ref()?;
+
+    let text = match &translation.translation_simple {
+        Some(text) => text.clone(),
+        None => translation
+            .numerus_forms
+            .iter()
+            .map(|form| form.text.as_str())
+            .collect::<Vec<_>>()
+            .join(" | "),
+    };
+
+    (!text.trim_matches(|c| c == ' ' || c == '|').is_empty()).then_some(text)
+}
+
+/// Words of a translation, without placeholders (`%1`, `%n`, `%L1`), markup tags,
+/// entities and keyboard accelerators.
+pub(crate) fn extract_words(text: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut word = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '<' => {
+                chars.by_ref().find(|c| *c == '>');
+                words.push(std::mem::take(&mut word));
+            }
+            '%' if chars
+                .peek()
+                .is_some_and(|next| *next == 'n' || *next == 'L' || next.is_ascii_digit()) =>
+            {
+                if chars.next_if_eq(&'L').is_none() {
+                    chars.next();
+                }
+                while chars.next_if(|c| c.is_ascii_digit()).is_some() {}
+                words.push(std::mem::take(&mut word));
+            }
+            '&' if chars.peek() == Some(&'&') => {
+                chars.next();
+                words.push(std::mem::take(&mut word));
+            }
+            '&' => {
+                let name: Vec<char> = chars.clone().take_while(|c| *c != ';').collect();
+                let is_entity = !name.is_empty()
+                    && chars.clone().nth(name.len()) == Some(';')
+                    && name.iter().all(|c| c.is_ascii_alphanumeric() || *c == '#');
+
+                if is_entity {
+                    chars.by_ref().find(|c| *c == ';');
+                    words.push(std::mem::take(&mut word));
+                }
+                // Otherwise, this is a keyboard accelerator: the word continues
+            }
+            c if c.is_alphanumeric() => word.push(c),
+            // Apostrophes within words, e.g. `don't` or `l'application`
+            '\'' | '’' if !word.is_empty() && chars.peek().is_some_and(|c| c.is_alphabetic()) => {
+                word.push('\'')
+            }
+            _ => words.push(std::mem::take(&mut word)),
+        }
+    }
+
+    words.push(word);
+    words.retain(|word| !word.is_empty());
+    words
+}
+
+#[cfg(test)]
+mod text_test {
+    use super::*;
+
+    #[test]
+    fn test_extract_words() {
+        assert_eq!(
+            extract_words("&Open %1 <b>files</b>&nbsp;now, %L2 don't %n times && more"),
+            vec!["Open", "files", "now", "don't", "times", "more"]
+        );
+        assert_eq!(extract_words("Sa&ve"), vec!["Save"]);
+    }
+}
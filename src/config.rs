@@ -0,0 +1,356 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use log::debug;
+use serde::Deserialize;
+
+use crate::options::{
+    LengthUnit, MatchStrategy, OutputFormat, ReportFormat, Severity, SortOrder, TermMatch,
+    language_matches,
+};
+use crate::tr;
+
+/// Name of the project configuration file, looked up in the working directory and its parents.
+pub const CONFIG_FILE_NAME: &str = "qt-ts-tools.toml";
+
+/// Project configuration, providing defaults to the commands. Command line arguments
+/// always take precedence over it. Relative paths are relative to the configuration file.
+///
+/// ### Example
+/// ```toml
+/// [groups]
+/// app = ["translations/app_de.ts", "translations/app_ja.ts"]
+///
+/// [languages.ja]
+/// skip-accelerators = true
+///
+/// [lint]
+/// severity = { capitalization = "off" }
+/// ```
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Config {
+    /// Groups of TS files, used as `@group` instead of an input path.
+    pub groups: BTreeMap<String, Vec<String>>,
+    /// Settings by language, e.g. `de` or `zh_CN`.
+    pub languages: BTreeMap<String, LanguageConfig>,
+    pub lint: LintConfig,
+    pub spell_check: SpellCheckConfig,
+    pub sort: SortConfig,
+    pub merge: MergeConfig,
+    pub diff: DiffConfig,
+    pub release: ReleaseConfig,
+    pub writer: WriterConfig,
+}
+
+/// Settings applying to the TS files of a language.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct LanguageConfig {
+    pub skip_accelerators: Option<bool>,
+    pub length_unit: Option<LengthUnit>,
+    /// Severities of lint rules, overriding the ones of the `[lint]` section.
+    pub severity: BTreeMap<String, Severity>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct LintConfig {
+    pub skip_accelerators: Vec<String>,
+    pub severity: BTreeMap<String, Severity>,
+    pub max_length_file: Option<String>,
+    pub length_unit: Option<LengthUnit>,
+    pub glossary: Vec<String>,
+    pub glossary_match: Option<TermMatch>,
+    pub format: Option<OutputFormat>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct SpellCheckConfig {
+    pub dictionary_dir: Vec<String>,
+    pub word_list: Vec<String>,
+    pub format: Option<OutputFormat>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct SortConfig {
+    pub order: Option<SortOrder>,
+}
+
+/// Settings of both `merge` and `merge-driver`.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct MergeConfig {
+    pub strategy: Option<MatchStrategy>,
+    pub fuzzy_threshold: Option<f64>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct DiffConfig {
+    pub strategy: Option<MatchStrategy>,
+    pub locations: bool,
+    pub order: bool,
+    pub format: Option<ReportFormat>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ReleaseConfig {
+    /// Directory in which `file.ts` is released as `file.qm` when no output path is given.
+    pub output_dir: Option<String>,
+}
+
+/// Style of the TS files written by the commands.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(default, rename_all = "kebab-case", deny_unknown_fields)]
+pub struct WriterConfig {
+    /// Number of indentation characters per level.
+    pub indent: usize,
+    pub indent_with_tabs: bool,
+}
+
+impl Default for WriterConfig {
+    fn default() -> Self {
+        WriterConfig {
+            indent: 2,
+            indent_with_tabs: false,
+        }
+    }
+}
+
+impl Config {
+    /// Parses a configuration, making its relative paths relative to `base_dir`.
+    pub fn parse(content: &str, base_dir: &Path) -> Result<Config, String> {
+        let mut config: Config = toml::from_str(content).map_err(|e| e.to_string())?;
+
+        if let Some(threshold) = config.merge.fuzzy_threshold
+            && !(0.0..=1.0).contains(&threshold)
+        {
+            return Err(tr!(
+                "cli-merge-fuzzy-threshold-invalid",
+                value = threshold.to_string()
+            ));
+        }
+
+        let resolve = |path: &mut String| {
+            *path = base_dir.join(&*path).to_string_lossy().into_owned();
+        };
+
+        config.groups.values_mut().flatten().for_each(resolve);
+        config.lint.glossary.iter_mut().for_each(resolve);
+        config.lint.max_length_file.iter_mut().for_each(resolve);
+        config
+            .spell_check
+            .dictionary_dir
+            .iter_mut()
+            .for_each(resolve);
+        config.spell_check.word_list.iter_mut().for_each(resolve);
+        config.release.output_dir.iter_mut().for_each(resolve);
+
+        Ok(config)
+    }
+
+    fn load(path: &Path) -> Result<Config, String> {
+        debug!("Loading configuration '{}'", path.display());
+
+        std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|content| Config::parse(&content, path.parent().unwrap_or(Path::new(""))))
+            .map_err(|e| {
+                tr!(
+                    "error-open-or-parse",
+                    file = path.display().to_string(),
+                    error = e
+                )
+            })
+    }
+
+    /// Expands an input path: `@group` designates every file of a group.
+    pub fn expand_input(&self, input: &str) -> Result<Vec<String>, String> {
+        match input.strip_prefix('@') {
+            None => Ok(vec![input.to_owned()]),
+            Some(group) => self
+                .groups
+                .get(group)
+                .cloned()
+                .ok_or_else(|| tr!("error-config-unknown-group", group = group)),
+        }
+    }
+
+    /// Expands an input path of a command working on a single file: `@group` is only accepted
+    /// when the group has a single file.
+    pub fn expand_single_input(&self, input: &str) -> Result<String, String> {
+        match <[String; 1]>::try_from(self.expand_input(input)?) {
+            Ok([path]) => Ok(path),
+            Err(_) => Err(tr!(
+                "error-config-group-not-single",
+                group = input.trim_start_matches('@')
+            )),
+        }
+    }
+
+    /// Settings of a language, the most specific entry being used (`zh_CN` over `zh`).
+    pub fn language(&self, language: &str) -> Option<&LanguageConfig> {
+        self.languages
+            .iter()
+            .filter(|(pattern, _)| language_matches(language, pattern))
+            .max_by_key(|(pattern, _)| pattern.len())
+            .map(|(_, config)| config)
+    }
+}
+
+/// Finds the configuration file in `directory` or its parents.
+fn discover(directory: &Path) -> Option<PathBuf> {
+    directory
+        .ancestors()
+        .map(|directory| directory.join(CONFIG_FILE_NAME))
+        .find(|path| path.is_file())
+}
+
+/// Path of a configuration file found in a parent of `directory`, relative to `directory`.
+/// This keeps the paths of the configuration relative to the working directory in reports.
+fn relative_to(path: &Path, directory: &Path) -> PathBuf {
+    let config_dir = path.parent().unwrap_or(Path::new(""));
+
+    match directory.strip_prefix(config_dir) {
+        Ok(rest) => rest
+            .components()
+            .map(|_| Path::new(".."))
+            .collect::<PathBuf>()
+            .join(CONFIG_FILE_NAME),
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+static CURRENT_CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Loads the configuration at `path`, or the one found from the working directory if `None`.
+/// Without any configuration file, the default configuration is used.
+pub fn initialize_config(path: Option<&str>) -> Result<(), String> {
+    let path = match path {
+        Some(path) => Some(PathBuf::from(path)),
+        None => std::env::current_dir().ok().and_then(|directory| {
+            let path = discover(&directory)?;
+            Some(relative_to(&path, &directory))
+        }),
+    };
+
+    let config = match path {
+        Some(path) => Config::load(&path)?,
+        None => Config::default(),
+    };
+
+    let _ = CURRENT_CONFIG.set(config);
+    Ok(())
+}
+
+/// Configuration of the project, the default one if none was loaded.
+pub fn current_config() -> &'static Config {
+    CURRENT_CONFIG.get_or_init(Config::default)
+}
+
+#[cfg(test)]
+mod config_test {
+    use super::*;
+
+    fn load_test_config() -> Config {
+        Config::load(&PathBuf::from("./test_data/config").join(CONFIG_FILE_NAME))
+            .expect("Configuration is loadable")
+    }
+
+    #[test]
+    fn test_load_config() {
+        let config = load_test_config();
+
+        assert_eq!(
+            config.expand_input("@app"),
+            Ok(vec![
+                "./test_data/config/translations/app_de.ts".to_owned(),
+                "./test_data/config/translations/app_ja.ts".to_owned()
+            ])
+        );
+        assert_eq!(
+            config.expand_input("other.ts"),
+            Ok(vec!["other.ts".to_owned()])
+        );
+        assert!(config.expand_input("@unknown").is_err());
+        assert_eq!(
+            config.expand_single_input("other.ts"),
+            Ok("other.ts".to_owned())
+        );
+        assert!(config.expand_single_input("@app").is_err());
+
+        assert_eq!(
+            config.lint.severity.get("capitalization"),
+            Some(&Severity::Off)
+        );
+        assert_eq!(config.lint.format, Some(OutputFormat::Github));
+        assert_eq!(
+            config.spell_check.dictionary_dir,
+            vec!["./test_data/config/dictionaries".to_owned()]
+        );
+        assert_eq!(config.sort.order, Some(SortOrder::Source));
+        assert_eq!(config.merge.strategy, Some(MatchStrategy::SourceComment));
+        assert_eq!(config.merge.fuzzy_threshold, Some(0.8));
+        assert!(config.diff.locations && !config.diff.order);
+        assert_eq!(config.diff.format, Some(ReportFormat::Json));
+        assert_eq!(config.writer.indent, 4);
+        assert!(!config.writer.indent_with_tabs);
+    }
+
+    #[test]
+    fn test_language_config() {
+        let config = load_test_config();
+
+        assert_eq!(
+            config.language("ja_JP").and_then(|ja| ja.skip_accelerators),
+            Some(true)
+        );
+        assert_eq!(
+            config.language("zh_TW").and_then(|zh| zh.length_unit),
+            Some(LengthUnit::Chars)
+        );
+        assert_eq!(
+            config.language("zh_CN").and_then(|zh| zh.length_unit),
+            Some(LengthUnit::Utf16)
+        );
+        assert!(config.language("de").is_none());
+    }
+
+    #[test]
+    fn test_discover_config() {
+        assert_eq!(
+            discover(Path::new("./test_data/config/translations")),
+            Some(PathBuf::from("./test_data/config").join(CONFIG_FILE_NAME))
+        );
+    }
+
+    #[test]
+    fn test_relative_to() {
+        assert_eq!(
+            relative_to(
+                &Path::new("/project").join(CONFIG_FILE_NAME),
+                Path::new("/project/translations/app")
+            ),
+            Path::new("../..").join(CONFIG_FILE_NAME)
+        );
+        assert_eq!(
+            relative_to(
+                &Path::new("/project").join(CONFIG_FILE_NAME),
+                Path::new("/project")
+            ),
+            PathBuf::from(CONFIG_FILE_NAME)
+        );
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(Config::parse("[lint]\nunknown = 1\n", Path::new("")).is_err());
+        assert!(Config::parse("[lint]\nlength-unit = \"bytes\"\n", Path::new("")).is_err());
+        assert!(Config::parse("[merge]\nfuzzy-threshold = 1.5\n", Path::new("")).is_err());
+    }
+}
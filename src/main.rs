@@ -6,8 +6,10 @@ use log::*;
 
 mod cli;
 mod commands;
+mod config;
 mod locale;
 mod logging;
+mod options;
 mod ts;
 
 fn main() {
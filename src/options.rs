@@ -0,0 +1,164 @@
+use std::fmt::{Display, Formatter};
+
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::ts::MessageNode;
+
+/// Severity of a lint rule. Only errors make the command fail.
+#[derive(ValueEnum, Clone, Copy, Debug, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Severity {
+    /// The rule is disabled
+    Off,
+    Warning,
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Off => write!(f, "off"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// Unit in which the length of a translation is measured.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum LengthUnit {
+    /// Unicode characters
+    #[default]
+    Chars,
+    /// UTF-16 code units, as measured by `QString::length`
+    Utf16,
+}
+
+impl LengthUnit {
+    pub fn measure(&self, text: &str) -> usize {
+        match self {
+            LengthUnit::Chars => text.chars().count(),
+            LengthUnit::Utf16 => text.encode_utf16().count(),
+        }
+    }
+}
+
+/// How words of a text are compared with the words of a glossary term.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TermMatch {
+    /// Words must match exactly (case-insensitive).
+    #[default]
+    Word,
+    /// Words may have any ending, e.g. "Workspaces" matches "Workspace". This is no stemming:
+    /// longer unrelated words match too.
+    Prefix,
+}
+
+/// Format in which diagnostics are reported.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OutputFormat {
+    /// One human readable line per diagnostic
+    #[default]
+    Text,
+    Json,
+    /// SARIF 2.1.0, understood by most code scanning tools
+    Sarif,
+    /// GitHub Actions workflow commands, shown as annotations in pull requests
+    Github,
+}
+
+/// Format of the reports of commands comparing translation files.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReportFormat {
+    /// Human readable sections
+    #[default]
+    Text,
+    Json,
+}
+
+/// How messages of both files are identified as being the same message.
+#[derive(ValueEnum, Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MatchStrategy {
+    /// Same ids if both messages have one, else same source, within a context. Disambiguation
+    /// comments are ignored: this is the historical behavior of the tool.
+    #[default]
+    IdOrSource,
+    /// Same ids, in any context. Messages without id never match.
+    Id,
+    /// Same source and disambiguation comment, in any context, which is how Qt identifies messages
+    /// without id. Messages moved to another context are matched.
+    SourceComment,
+    /// Same source and disambiguation comment, within a context.
+    SourceCommentContext,
+}
+
+impl MatchStrategy {
+    /// Whether two messages are the same message under this strategy, contexts aside.
+    pub fn matches(self, left: &MessageNode, right: &MessageNode) -> bool {
+        match self {
+            MatchStrategy::IdOrSource => {
+                if let Some(left_id) = &left.id
+                    && let Some(right_id) = &right.id
+                {
+                    return left_id == right_id;
+                }
+
+                left.source == right.source
+            }
+            MatchStrategy::Id => left.id.is_some() && left.id == right.id,
+            MatchStrategy::SourceComment | MatchStrategy::SourceCommentContext => {
+                left.source == right.source && left.comment == right.comment
+            }
+        }
+    }
+
+    /// Whether each left message looks for its match among the right messages, as merging always
+    /// did, rather than each right message updating a single left message.
+    pub fn is_left_driven(self) -> bool {
+        self == MatchStrategy::IdOrSource
+    }
+
+    /// Whether messages are looked up in the other contexts when not found in their own.
+    pub fn across_contexts(self) -> bool {
+        matches!(self, MatchStrategy::Id | MatchStrategy::SourceComment)
+    }
+}
+
+/// Order of the messages within each context of a sorted file.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SortOrder {
+    /// By id, then by first location, as `lupdate` lists them
+    #[default]
+    Location,
+    /// By source text, then by disambiguation comment
+    Source,
+}
+
+/// Whether `language` (e.g. `zh_CN`) is designated by `pattern`, either exactly or by
+/// its language part (e.g. `zh`). Both `_` and `-` are accepted as separators.
+pub fn language_matches(language: &str, pattern: &str) -> bool {
+    let language = language.to_lowercase().replace('-', "_");
+    let pattern = pattern.to_lowercase().replace('-', "_");
+
+    !pattern.is_empty() && (language == pattern || language.starts_with(&format!("{pattern}_")))
+}
+
+#[cfg(test)]
+mod options_test {
+    use super::*;
+
+    #[test]
+    fn test_language_matches() {
+        assert!(language_matches("zh_CN", "zh"));
+        assert!(language_matches("zh_CN", "zh-cn"));
+        assert!(!language_matches("zh_CN", "zh_TW"));
+        assert!(!language_matches("ja", "j"));
+    }
+}
@@ -1,9 +1,11 @@
 use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
 use std::io::{BufWriter, Write};
 
 use log::debug;
 use serde::{Deserialize, Serialize};
 
+use crate::config::current_config;
 use crate::tr;
 
 // This file defines the schema matching (or trying to match?) Qt's XSD
@@ -27,6 +29,35 @@ pub enum TranslationType {
     Vanished,
 }
 
+/// Status of a message, from the type of its translation. A message without translation is
+/// unfinished.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum MessageStatus {
+    Finished,
+    Unfinished,
+    Obsolete,
+    Vanished,
+}
+
+impl MessageStatus {
+    /// Whether the message is still used by the application, i.e. neither obsolete nor vanished.
+    pub fn is_in_use(self) -> bool {
+        !matches!(self, MessageStatus::Obsolete | MessageStatus::Vanished)
+    }
+}
+
+/// Name of the status, as written in the `type` attribute of translations.
+impl Display for MessageStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MessageStatus::Finished => write!(f, "finished"),
+            MessageStatus::Unfinished => write!(f, "unfinished"),
+            MessageStatus::Obsolete => write!(f, "obsolete"),
+            MessageStatus::Vanished => write!(f, "vanished"),
+        }
+    }
+}
+
 #[derive(Debug, Eq, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum YesNo {
@@ -35,7 +66,7 @@ pub enum YesNo {
 }
 
 /// Root node of the translation file.
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename = "TS")]
 pub struct TSNode {
     /// Defines the version of the TS format, although unused by this tool.
@@ -95,7 +126,7 @@ pub struct TSNode {
 }
 
 /// Context and its associated translated message.
-#[derive(Debug, Eq, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Eq, Clone, Deserialize, Serialize, PartialEq)]
 pub struct ContextNode {
     /// Unique name of the context
     pub name: String,
@@ -110,13 +141,13 @@ pub struct ContextNode {
     pub encoding: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct DependenciesNode {
     #[serde(rename = "dependency")]
     pub dependencies: Vec<Dependency>,
 }
 
-#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct Dependency {
     pub catalog: String,
 }
@@ -185,6 +216,9 @@ pub struct MessageNode {
     pub loc_feature: Option<String>,
     #[serde(rename = "extra-loc-blank", skip_serializing_if = "Option::is_none")]
     pub loc_blank: Option<String>,
+    /// Maximum length allowed for the translation
+    #[serde(rename = "extra-maxlength", skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<String>,
 }
 
 /// Translation node that indicates an actual translation for a message.
@@ -228,6 +262,21 @@ pub struct NumerusFormNode {
     pub variants: Option<YesNo>,
 }
 
+impl MessageNode {
+    pub fn status(&self) -> MessageStatus {
+        match self
+            .translation
+            .as_ref()
+            .map(|translation| &translation.translation_type)
+        {
+            None | Some(Some(TranslationType::Unfinished)) => MessageStatus::Unfinished,
+            Some(None) | Some(Some(TranslationType::Finished)) => MessageStatus::Finished,
+            Some(Some(TranslationType::Obsolete)) => MessageStatus::Obsolete,
+            Some(Some(TranslationType::Vanished)) => MessageStatus::Vanished,
+        }
+    }
+}
+
 impl PartialOrd<Self> for MessageNode {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
@@ -305,8 +354,17 @@ impl Ord for ContextNode {
     }
 }
 
+/// Reads and parses the TS file at `path`.
+pub fn read_from_file(path: &str) -> Result<TSNode, String> {
+    let reader = quick_xml::Reader::from_file(path)
+        .map_err(|e| tr!("error-open-or-parse", file = path, error = e.to_string()))?;
+
+    quick_xml::de::from_reader(reader.into_inner())
+        .map_err(|e| tr!("error-ts-file-parse", file = path, error = e.to_string()))
+}
+
 /// Writes the output TS file to the specified output (file or stdout).
-/// This writer will auto indent/pretty print. It will always expand empty nodes, e.g.
+/// This writer will auto indent/pretty print, as configured by the project configuration. It will always expand empty nodes, e.g.
 /// `<name></name>` instead of `<name/>`.
 pub fn write_to_output(output_path: &Option<String>, node: &TSNode) -> Result<(), String> {
     debug!(
@@ -336,7 +394,14 @@ pub fn write_to_output(output_path: &Option<String>, node: &TSNode) -> Result<()
     let mut output_buffer =
         String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<!DOCTYPE TS>\n");
     let mut ser = quick_xml::se::Serializer::new(&mut output_buffer);
-    ser.indent(' ', 2).expand_empty_elements(true);
+    let writer_config = &current_config().writer;
+    let indent_char = if writer_config.indent_with_tabs {
+        '\t'
+    } else {
+        ' '
+    };
+    ser.indent(indent_char, writer_config.indent)
+        .expand_empty_elements(true);
 
     match node.serialize(ser) {
         Ok(_) => {
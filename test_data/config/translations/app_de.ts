@@ -0,0 +1,40 @@
+<?xml version="1.0" encoding="utf-8"?>
+<!DOCTYPE TS>
+<TS version="2.1" sourcelanguage="en" language="de">
+    <context>
+        <name>MainWindow</name>
+        <message>
+            <location filename="mainwindow.cpp" line="10"/>
+            <source>&amp;File</source>
+            <translation>&amp;Datei</translation>
+        </message>
+        <message>
+            <location filename="mainwindow.cpp" line="11"/>
+            <source>&amp;Open</source>
+            <translation>Öffnen</translation>
+        </message>
+        <message>
+            <location filename="mainwindow.cpp" line="12"/>
+            <source>Save</source>
+            <translation>&amp;Speichern</translation>
+        </message>
+        <message>
+            <location filename="mainwindow.cpp" line="13"/>
+            <source>&amp;Quit</source>
+            <translation>Been&amp;den</translation>
+        </message>
+        <message>
+            <location filename="mainwindow.cpp" line="14"/>
+            <source>Drag &amp; Drop &amp;&amp; &amp;Copy</source>
+            <translation>Ziehen &amp; Ablegen &amp;&amp; &amp;Kopieren</translation>
+        </message>
+    </context>
+    <context>
+        <name>Toolbar</name>
+        <message>
+            <location filename="toolbar.cpp" line="4"/>
+            <source>&amp;Edit</source>
+            <translation>&amp;Datei bearbeiten</translation>
+        </message>
+    </context>
+</TS>